@@ -1,6 +1,196 @@
-use std::process::Command;
+use std::{env, fmt::Write as _, fs, path::Path, process::Command};
+
+// Shared with the library so that the manifest format has one parser. Only
+// part of either module is used here.
+#[allow(dead_code)]
+#[path = "src/error.rs"]
+mod error;
+
+#[allow(dead_code)]
+#[path = "src/manifest.rs"]
+mod manifest;
+
+use manifest::Manifest;
 
 fn main() {
+    generate_days();
+    generate_sample_tests();
+
+    if env::var_os("CARGO_FEATURE_LP").is_some() {
+        link_cbc();
+    }
+}
+
+/// Writes a `pub mod y<year>` for every `src/y<year>/` to `$OUT_DIR/days.rs`,
+/// which `lib.rs` includes. Each declares a module per `day<NN>.rs` in it and
+/// a `register` function for their `Day<NN>` solutions, and is compiled only
+/// with the `y<year>` feature. Files that do not fit this layout turn into
+/// `compile_error!`s.
+fn generate_days() {
+    println!("cargo:rerun-if-changed=src");
+
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+
+    let mut years = fs::read_dir(&src)
+        .unwrap()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let year = name.strip_prefix('y')?.parse::<u16>().ok()?;
+            Some((year, entry.path()))
+        })
+        .collect::<Vec<_>>();
+
+    years.sort();
+
+    let mut code = String::new();
+
+    for (year, dir) in &years {
+        let mut files = fs::read_dir(dir)
+            .unwrap()
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| name.starts_with("day") && name.ends_with(".rs"))
+            .collect::<Vec<_>>();
+
+        files.sort();
+
+        let mut mods = String::new();
+        let mut registers = String::new();
+
+        for file in files {
+            let path = dir.join(&file);
+            let display = format!("src/y{}/{}", year, file);
+
+            let Some(day) = file.strip_prefix("day")
+                .and_then(|s| s.strip_suffix(".rs"))
+                .filter(|s| s.len() == 2)
+                .and_then(|s| s.parse::<u8>().ok())
+                .filter(|day| (1..=25).contains(day)) else {
+                writeln!(
+                    mods,
+                    "    compile_error!({:?});",
+                    format!("{}: expected a file named `day01.rs` to `day25.rs`", display),
+                ).unwrap();
+                continue;
+            };
+
+            writeln!(mods, "    #[path = {:?}]\n    pub mod day{:02};", path.display().to_string(), day).unwrap();
+
+            let contents = fs::read_to_string(&path).unwrap_or_default();
+
+            if defines_struct(&contents, &format!("Day{:02}", day)) {
+                writeln!(registers, "        registry.register({}, {}, day{:02}::Day{:02});", year, day, day, day).unwrap();
+            } else {
+                writeln!(
+                    registers,
+                    "        compile_error!({:?});",
+                    format!("{}: expected a `pub struct Day{:02}` that implements `Solution`", display, day),
+                ).unwrap();
+            }
+        }
+
+        writeln!(
+            code,
+            "#[cfg(feature = \"y{year}\")]\npub mod y{year} {{\n{mods}\n    pub fn register(registry: &mut crate::registry::Registry) {{\n{registers}    }}\n}}\n",
+        ).unwrap();
+    }
+
+    // `registry` goes unused if no year is enabled.
+    writeln!(code, "#[allow(unused_variables)]\nfn register_days(registry: &mut registry::Registry) {{").unwrap();
+    for (year, _) in &years {
+        writeln!(code, "    #[cfg(feature = \"y{}\")]\n    y{}::register(registry);", year, year).unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+
+    write_if_changed(&Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs"), &code);
+}
+
+/// Whether `source` has a line starting with `pub struct <name>`.
+fn defines_struct(source: &str, name: &str) -> bool {
+    source.lines().any(|line| {
+        line.strip_prefix("pub struct ")
+            .and_then(|rest| rest.strip_prefix(name))
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+    })
+}
+
+/// Leaves `path` alone if it already has `contents`, so that rerunning the
+/// build script does not recompile whatever includes it.
+fn write_if_changed(path: &Path, contents: &str) {
+    if fs::read_to_string(path).is_ok_and(|old| old == contents) {
+        return;
+    }
+
+    fs::write(path, contents).unwrap();
+}
+
+/// Writes one `#[test]` per entry of every `inputs/<year>/answers.toml` to
+/// `$OUT_DIR/samples.rs`, which `tests/samples.rs` includes. The tests of a
+/// year are compiled only with its feature, and `partN.requires = "<feature>"`
+/// does the same for the test of one part.
+fn generate_sample_tests() {
+    println!("cargo:rerun-if-changed=inputs");
+
+    let mut tests = String::new();
+
+    let mut years = fs::read_dir("inputs")
+        .map(|entries| entries.flatten()
+            .filter_map(|entry| entry.file_name().to_str()?.parse::<u16>().ok())
+            .collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    years.sort();
+
+    for year in years {
+        let path = Path::new("inputs").join(year.to_string()).join("answers.toml");
+
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let manifest = Manifest::parse(&contents)
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+        for (stem, answers) in manifest.sections() {
+            let day = stem.strip_prefix("day")
+                .and_then(|s| s.get(..2))
+                .and_then(|s| s.parse::<u8>().ok())
+                .unwrap_or_else(|| panic!("{}: `[{}]` does not name a day's input", path.display(), stem));
+
+            // `param.<name> = <value>` entries are passed to every part.
+            let params = answers.iter()
+                .filter_map(|(key, value)| Some((key.strip_prefix("param.")?, value.as_str())))
+                .collect::<Vec<_>>();
+
+            let requires = |part: &str| answers.iter()
+                .find(|(key, _)| key.strip_suffix(".requires") == Some(part))
+                .map(|(_, feature)| format!("#[cfg(feature = {:?})]\n", feature))
+                .unwrap_or_default();
+
+            let expected = answers.iter()
+                .filter(|(key, _)| !key.starts_with("param.") && !key.ends_with(".requires"));
+
+            for (key, expected) in expected {
+                let part = key.strip_prefix("part")
+                    .and_then(|s| s.parse::<u8>().ok())
+                    .unwrap_or_else(|| panic!("{}: `{}` in `[{}]` is not `partN`", path.display(), key, stem));
+
+                writeln!(
+                    tests,
+                    "#[test]\n#[cfg(feature = \"y{}\")]\n{}fn y{}_{}_{}() {{\n    check({}, {}, {}, \"inputs/{}/{}.txt\", &{:?}, {:?});\n}}\n",
+                    year, requires(key), year, stem, key, year, day, part, year, stem, params, expected,
+                ).unwrap();
+            }
+        }
+    }
+
+    write_if_changed(&Path::new(&env::var("OUT_DIR").unwrap()).join("samples.rs"), &tests);
+}
+
+#[allow(clippy::collapsible_if)]
+fn link_cbc() {
     // Try to find CBC library path automatically
 
     // On macOS with Homebrew, try to get the path dynamically
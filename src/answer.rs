@@ -0,0 +1,101 @@
+use std::fmt;
+
+/// A puzzle answer. Numeric variants compare by value, so `Int(5)` equals
+/// `UInt(5)`.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    U128(u128),
+    Text(String),
+    /// Multi-line text, e.g., letters drawn in ASCII art.
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    fn as_i128(&self) -> Option<i128> {
+        match *self {
+            Answer::Int(x) => Some(x as i128),
+            Answer::UInt(x) => Some(x as i128),
+            Answer::U128(x) => i128::try_from(x).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::U128(x), Answer::U128(y)) => x == y,
+            (Answer::Text(x), Answer::Text(y)) => x == y,
+            (Answer::Lines(x), Answer::Lines(y)) => x == y,
+            _ => matches!((self.as_i128(), other.as_i128()), (Some(x), Some(y)) if x == y),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::UInt(x) => write!(f, "{}", x),
+            Answer::U128(x) => write!(f, "{}", x),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(x: $ty) -> Self {
+                    Answer::$variant(x.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    i32 => Int,
+    i64 => Int,
+    u32 => UInt,
+    u64 => UInt,
+    u128 => U128,
+    String => Text,
+    &str => Text,
+);
+
+impl From<usize> for Answer {
+    fn from(x: usize) -> Self {
+        Answer::UInt(x as u64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::answer::Answer;
+
+    #[test]
+    fn test_answer_eq() {
+        assert_eq!(Answer::Int(5), Answer::UInt(5));
+        assert_eq!(Answer::UInt(5), Answer::U128(5));
+        assert_ne!(Answer::Int(-1), Answer::U128(u128::MAX));
+        assert_ne!(Answer::Int(5), Answer::Text(String::from("5")));
+        assert_eq!(Answer::from(7usize), Answer::from(7i64));
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::Int(-3).to_string(), "-3");
+        assert_eq!(Answer::U128(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(
+            Answer::Lines(vec![String::from("#.#"), String::from(".#.")]).to_string(),
+            "#.#\n.#.",
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::time::{Duration, Instant};
+
+/// Runs `f` `warmup` times untimed, then `iterations` times timed.
+pub fn measure(warmup: usize, iterations: usize, mut f: impl FnMut()) -> Vec<Duration> {
+    for _ in 0..warmup {
+        f();
+    }
+
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    /// Samples outside `[Q1 - 1.5 IQR, Q3 + 1.5 IQR]` but within 3 IQR.
+    pub mild_outliers: usize,
+    /// Samples outside `[Q1 - 3 IQR, Q3 + 3 IQR]`.
+    pub severe_outliers: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos = samples.iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+
+        nanos.sort_by(f64::total_cmp);
+
+        let n = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter()
+            .map(|x| (x - mean) * (x - mean))
+            .sum::<f64>() / n;
+
+        let (q1, q3) = (percentile(&nanos, 25.0), percentile(&nanos, 75.0));
+        let iqr = q3 - q1;
+
+        let outside = |k: f64| nanos.iter()
+            .filter(|&&x| x < q1 - k * iqr || x > q3 + k * iqr)
+            .count();
+
+        let severe_outliers = outside(3.0);
+
+        Some(Self {
+            samples: nanos.len(),
+            min: from_nanos(nanos[0]),
+            median: from_nanos(percentile(&nanos, 50.0)),
+            mean: from_nanos(mean),
+            p95: from_nanos(percentile(&nanos, 95.0)),
+            stddev: from_nanos(variance.sqrt()),
+            mild_outliers: outside(1.5) - severe_outliers,
+            severe_outliers,
+        })
+    }
+}
+
+/// Linearly interpolated percentile of an ascending `sorted` slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::bench::{Stats, measure};
+
+    #[test]
+    fn test_measure() {
+        let mut count = 0;
+
+        let samples = measure(3, 5, || count += 1);
+
+        assert_eq!(count, 8);
+        assert_eq!(samples.len(), 5);
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut samples = (1..=19).map(Duration::from_micros).collect::<Vec<_>>();
+        samples.push(Duration::from_micros(110));
+
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(10_500));
+        assert_eq!(stats.mean, Duration::from_micros(15));
+        assert_eq!(stats.p95, Duration::from_nanos(23_550));
+        assert_eq!(stats.mild_outliers, 0);
+        assert_eq!(stats.severe_outliers, 1);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+}
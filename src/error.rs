@@ -0,0 +1,83 @@
+use std::fmt;
+
+/// Error raised while parsing or solving a puzzle. `line` is 1-based and
+/// points into the puzzle input, if the error can be attributed to a line.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AocError {
+    pub line: Option<usize>,
+    pub reason: String,
+    /// Whether this build cannot solve the puzzle at all, e.g., because an
+    /// optional dependency is left out, as opposed to failing on the input.
+    pub unavailable: bool,
+}
+
+impl AocError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            reason: reason.into(),
+            unavailable: false,
+        }
+    }
+
+    pub fn unavailable(reason: impl Into<String>) -> Self {
+        Self {
+            unavailable: true,
+            ..Self::new(reason)
+        }
+    }
+
+    pub fn with_line(self, line: usize) -> Self {
+        Self {
+            line: Some(line),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.unavailable {
+            write!(f, "unavailable in this build, ")?;
+        }
+
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.reason),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+pub type Result<T, E = AocError> = std::result::Result<T, E>;
+
+/// Maps every line of `input` with `f`, attributing errors to their line.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T>,
+) -> Result<Vec<T>> {
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.with_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::{AocError, parse_lines};
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1\n2\n3", |line| line.parse::<i32>().map_err(|_| AocError::new("nan"))),
+            Ok(vec![1, 2, 3]),
+        );
+
+        let err = parse_lines("1\nx\n3", |line| line.parse::<i32>().map_err(|_| AocError::new("nan")))
+            .unwrap_err();
+
+        assert_eq!(err.line, Some(2));
+        assert_eq!(err.to_string(), "line 2: nan");
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::manifest::Manifest;
+use crate::params::Params;
+
+const INPUTS_DIR: &str = "inputs";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InputKind {
+    Sample,
+    Real,
+}
+
+/// Candidate file names for `(year, day, part)`, most specific first, e.g.,
+/// `inputs/2025/day11_sample_part1.txt` before `inputs/2025/day11_sample.txt`.
+pub fn candidates(year: u16, day: u8, part: u8, kind: InputKind) -> Vec<PathBuf> {
+    let dir = Path::new(INPUTS_DIR).join(year.to_string());
+
+    let stem = match kind {
+        InputKind::Sample => format!("day{:02}_sample", day),
+        InputKind::Real => format!("day{:02}", day),
+    };
+
+    vec![
+        dir.join(format!("{}_part{}.txt", stem, part)),
+        dir.join(format!("{}.txt", stem)),
+    ]
+}
+
+pub fn discover(year: u16, day: u8, part: u8, kind: InputKind) -> io::Result<PathBuf> {
+    let candidates = candidates(year, day, part, kind);
+
+    match candidates.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(io::Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "no {} input for {} day {} part {} (looked for {})",
+                match kind {
+                    InputKind::Sample => "sample",
+                    InputKind::Real => "real",
+                },
+                year,
+                day,
+                part,
+                candidates.iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        )),
+    }
+}
+
+pub fn read(path: &Path) -> io::Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+pub fn read_stdin() -> io::Result<String> {
+    io::read_to_string(io::stdin())
+}
+
+/// The `param.<name>` entries of the sample at `path` in
+/// `inputs/<year>/answers.toml`, which its expected answers rely on.
+pub fn sample_params(year: u16, path: &Path) -> io::Result<Params> {
+    let answers = Path::new(INPUTS_DIR).join(year.to_string()).join("answers.toml");
+
+    let contents = match std::fs::read_to_string(&answers) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Params::default()),
+        Err(e) => return Err(io::Error::new(e.kind(), format!("{}: {}", answers.display(), e))),
+    };
+
+    let manifest = Manifest::parse(&contents)
+        .map_err(|e| io::Error::new(ErrorKind::InvalidData, format!("{}: {}", answers.display(), e)))?;
+
+    let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+
+    Ok(Params::new(
+        manifest.sections()
+            .filter(|&(section, _)| section == stem)
+            .flat_map(|(_, entries)| entries)
+            .filter_map(|(key, value)| Some((key.strip_prefix("param.")?.to_string(), value.clone()))),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use crate::input::{InputKind, candidates, sample_params};
+    use crate::params::Params;
+
+    #[test]
+    fn test_candidates() {
+        assert_eq!(
+            candidates(2025, 11, 2, InputKind::Sample),
+            vec![
+                PathBuf::from("inputs/2025/day11_sample_part2.txt"),
+                PathBuf::from("inputs/2025/day11_sample.txt"),
+            ],
+        );
+
+        assert_eq!(
+            candidates(2025, 3, 1, InputKind::Real),
+            vec![
+                PathBuf::from("inputs/2025/day03_part1.txt"),
+                PathBuf::from("inputs/2025/day03.txt"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_sample_params() {
+        assert_eq!(
+            sample_params(2025, Path::new("inputs/2025/day08_sample.txt")).unwrap(),
+            Params::new([("connections".to_string(), "10".to_string())]),
+        );
+        assert!(sample_params(2025, Path::new("inputs/2025/day01_sample.txt")).unwrap().is_empty());
+    }
+}
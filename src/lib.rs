@@ -1,3 +1,66 @@
-pub mod y2025;
+pub mod utils;
 
-pub mod utils;
\ No newline at end of file
+pub mod input;
+
+pub mod runner;
+
+pub mod bench;
+
+pub mod solution;
+
+pub mod registry;
+
+pub mod answer;
+
+pub mod error;
+
+pub mod manifest;
+
+pub mod store;
+
+pub mod scaffold;
+
+pub mod json;
+
+pub mod params;
+
+pub mod trace;
+
+pub mod watch;
+
+// `pub mod y<year>` for every `src/y<year>/` behind its `y<year>` feature, with
+// a module per `day<NN>.rs` in it, and `register_days`. See `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+use crate::answer::Answer;
+use crate::error::{AocError, Result};
+use crate::params::Params;
+use crate::registry::Registry;
+use crate::runner::{RunOptions, Status};
+
+/// Parses `input` and solves `part` of `(year, day)` with it. A part that
+/// panics, e.g., with `todo!()` because it is not finished yet, fails.
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<Answer> {
+    solve_with(year, day, part, input, &Params::default())
+}
+
+/// Like [`solve`], overriding some of the day's parameters.
+pub fn solve_with(year: u16, day: u8, part: u8, input: &str, params: &Params) -> Result<Answer> {
+    let registry = Registry::all();
+    let solution = registry.get(year, day)
+        .ok_or_else(|| AocError::new(format!("{} day {} not implemented", year, day)))?;
+
+    let options = RunOptions {
+        params: params.clone(),
+        ..Default::default()
+    };
+
+    match runner::run_parts(solution, input, &[part], &options).parts.remove(0).status {
+        Status::Solved(answer) => Ok(answer),
+        Status::Failed(e) => Err(e),
+        status => Err(AocError::new(format!(
+            "{} day {} part {}: {}",
+            year, day, part, status.message().unwrap_or_else(|| status.label().to_string()),
+        ))),
+    }
+}
@@ -1,41 +1,936 @@
-use clap::Parser;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
-use aoc25::y2025;
+use clap::{Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+
+use aoc25::bench::{self, Stats};
+use aoc25::input::{self, InputKind};
+use aoc25::json::Json;
+use aoc25::params::{self, Params};
+use aoc25::registry::Registry;
+use aoc25::runner::{self, PartResult, RunOptions, Status};
+use aoc25::scaffold;
+use aoc25::solution::{AnySolution, DEFAULT_IMPL};
+use aoc25::store::{self, AnswerStore, Entry};
+use aoc25::trace::{self, Level};
+use aoc25::watch::Watcher;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    target: Target,
+
+    /// Rerun whenever the input file changes, until interrupted
     #[arg(short, long)]
-    year: u16,
+    watch: bool,
+
+    /// Print results as text or as one JSON record per line
+    #[arg(long, value_enum, global = true, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Show the solvers' debug events on stderr, or also their trace events
+    /// with `-vv`. Needs the `trace` feature.
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// A single (year, day, part) selection and where to read its input from.
+#[derive(clap::Args, Debug)]
+struct Target {
+    /// Defaults to the latest year compiled in
     #[arg(short, long)]
+    year: Option<u16>,
+
+    #[arg(short, long, required = true)]
+    day: Option<u8>,
+
+    #[arg(short, long, value_enum, default_value_t = PartArg::Both)]
+    part: PartArg,
+
+    /// Read the puzzle input from this file instead of stdin
+    #[arg(short, long, conflicts_with_all = ["sample", "real"])]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    kind: KindArgs,
+
+    /// Run this implementation instead of the default one, see `crosscheck`
+    #[arg(long = "impl", value_name = "NAME")]
+    implementation: Option<String>,
+
+    /// Override one of the day's parameters, see `list-params`
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = params::parse_pair)]
+    params: Vec<(String, String)>,
+}
+
+impl Target {
+    fn year_day(&self, registry: &Registry) -> (u16, u8) {
+        // `clap` enforces a day for a single run.
+        (latest_year(registry, self.year), self.day.unwrap())
+    }
+
+    fn params(&self) -> Params {
+        Params::new(self.params.iter().cloned())
+    }
+
+    fn run_options(&self) -> RunOptions {
+        RunOptions {
+            params: self.params(),
+            implementation: self.implementation.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn implementation(&self) -> &str {
+        self.implementation.as_deref().unwrap_or(DEFAULT_IMPL)
+    }
+
+    /// Exits if none of the selected parts has the implementation asked for.
+    /// The others run the default one, see `runner::implementation_for`.
+    fn check_implementation(&self, solution: &dyn AnySolution) {
+        let name = self.implementation();
+        let parts = self.part.parts();
+
+        if parts.iter().any(|&part| solution.impls(part).contains(&name)) {
+            return;
+        }
+
+        exit_with(format!(
+            "no selected part has an implementation `{}` ({})",
+            name,
+            parts.iter()
+                .map(|&part| format!("part {}: {}", part, solution.impls(part).join(", ")))
+                .join("; "),
+        ));
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct KindArgs {
+    /// Use the sample input under `inputs/<year>/`
+    #[arg(long, conflicts_with = "real")]
+    sample: bool,
+
+    /// Use the real input under `inputs/<year>/`
+    #[arg(long)]
+    real: bool,
+}
+
+impl KindArgs {
+    fn input_kind(&self) -> Option<InputKind> {
+        match (self.sample, self.real) {
+            (true, _) => Some(InputKind::Sample),
+            (_, true) => Some(InputKind::Real),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run every implemented day against its discovered input
+    ///
+    /// Exits with an error if any part fails, panics or times out.
+    All {
+        /// Only run this year
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Only run these days, e.g. `5` or `3-7`
+        #[arg(short, long)]
+        days: Option<DayRange>,
+
+        #[command(flatten)]
+        kind: KindArgs,
+
+        /// Give up on a parse or part after this long, e.g. `500ms` or `10s`
+        #[arg(short, long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+    },
+    /// Benchmark a solver against an in-memory input
+    Bench {
+        #[command(flatten)]
+        target: Target,
+
+        /// Untimed runs before measuring
+        #[arg(short, long, default_value_t = 10)]
+        warmup: usize,
+
+        /// Timed runs
+        #[arg(short = 'n', long, default_value_t = 100)]
+        iterations: usize,
+    },
+    /// Run a solver on its real input and record its answers as accepted
+    Accept {
+        #[command(flatten)]
+        target: Target,
+    },
+    /// Rerun every accepted answer against the real inputs and flag mismatches
+    ///
+    /// Exits with an error if any answer differs or cannot be computed, or if
+    /// an input changed since its answers were accepted.
+    Verify {
+        /// Give up on a parse or part after this long, e.g. `500ms` or `10s`
+        #[arg(short, long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+    },
+    /// Run every implementation of each part on the same input and flag any
+    /// that disagree with the default one
+    Crosscheck {
+        #[command(flatten)]
+        target: Target,
+
+        /// Give up on a parse or part after this long, e.g. `500ms` or `10s`
+        #[arg(short, long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+    },
+    /// List the parameters each day accepts through `--param`
+    ListParams {
+        /// Only list this year
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Only list this day
+        #[arg(short, long)]
+        day: Option<u8>,
+    },
+    /// Scaffold a solver stub and a sample input for a new day
+    New {
+        /// Defaults to the latest year compiled in
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        #[arg(short, long)]
+        day: u8,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+enum PartArg {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartArg {
+    fn parts(self) -> &'static [u8] {
+        match self {
+            PartArg::One => &[1],
+            PartArg::Two => &[2],
+            PartArg::Both => &[1, 2],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DayRange {
+    first: u8,
+    last: u8,
+}
+
+impl DayRange {
+    fn contains(&self, day: u8) -> bool {
+        self.first <= day && day <= self.last
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s: &str| s.trim().parse::<u8>()
+            .map_err(|_| format!("invalid day `{}`", s));
+
+        let (first, last) = match s.split_once('-') {
+            Some((first, last)) => (parse(first)?, parse(last)?),
+            None => (parse(s)?, parse(s)?),
+        };
+
+        if first > last {
+            return Err(format!("empty day range `{}`", s));
+        }
+
+        Ok(Self { first, last })
+    }
+}
+
+/// Parses `500ms`, `10s` or `2m`. A bare number is in seconds.
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    let (n, unit) = match s.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(i) => s.split_at(i),
+        None => (s, "s"),
+    };
+
+    let n = n.trim().parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite() && *n >= 0.0)
+        .ok_or_else(|| format!("invalid timeout `{}`", s))?;
+
+    match unit {
+        "ms" => Ok(Duration::from_secs_f64(n / 1e3)),
+        "s" => Ok(Duration::from_secs_f64(n)),
+        "m" => Ok(Duration::from_secs_f64(n * 60.0)),
+        _ => Err(format!("unknown unit `{}` in timeout `{}`, expected `ms`, `s` or `m`", unit, s)),
+    }
+}
+
+/// `year`, or else the latest year with a solution compiled in.
+fn latest_year(registry: &Registry, year: Option<u16>) -> u16 {
+    year.or_else(|| registry.years().last().copied())
+        .unwrap_or_else(|| exit_with("no year is compiled in, enable one of the `y<year>` features"))
+}
+
+/// Groups `parts` by the input file they resolve to, so that an input shared
+/// by several parts is parsed only once.
+fn discover_inputs(
+    year: u16,
     day: u8,
+    parts: &[u8],
+    kind: InputKind,
+) -> Vec<(Vec<u8>, std::io::Result<PathBuf>)> {
+    let mut inputs: Vec<(Vec<u8>, std::io::Result<PathBuf>)> = vec![];
+
+    for &part in parts {
+        let path = input::discover(year, day, part, kind);
+
+        match inputs.iter_mut().find(|(_, p)| matches!((p, &path), (Ok(p), Ok(path)) if p == path)) {
+            Some((parts, _)) => parts.push(part),
+            None => inputs.push((vec![part], path)),
+        }
+    }
+
+    inputs
+}
+
+/// An input read for some of a target's parts.
+struct TargetInput {
+    parts: Vec<u8>,
+    /// `None` for stdin.
+    path: Option<PathBuf>,
+    input: String,
+}
+
+fn read_inputs(target: &Target, year: u16, day: u8) -> std::io::Result<Vec<TargetInput>> {
+    let parts = target.part.parts().to_vec();
+
+    if let Some(path) = &target.input {
+        return Ok(vec![TargetInput { parts, path: Some(path.clone()), input: input::read(path)? }]);
+    }
+
+    let Some(kind) = target.kind.input_kind() else {
+        return Ok(vec![TargetInput { parts, path: None, input: input::read_stdin()? }]);
+    };
+
+    discover_inputs(year, day, &parts, kind)
+        .into_iter()
+        .map(|(parts, path)| {
+            let path = path?;
+            let input = input::read(&path)?;
+            Ok(TargetInput { parts, path: Some(path), input })
+        })
+        .collect()
+}
+
+/// A part that did not run because its input is missing.
+fn no_input(part: u8) -> PartResult {
+    PartResult {
+        part,
+        implementation: DEFAULT_IMPL.to_string(),
+        status: Status::NoInput,
+        elapsed: Duration::ZERO,
+    }
+}
+
+/// The JSON record for one part. Timings are left out without a
+/// `parse_elapsed`, i.e., if nothing ran.
+fn part_record(
+    year: u16,
+    day: u8,
+    path: Option<&Path>,
+    res: &PartResult,
+    parse_elapsed: Option<Duration>,
+) -> Json {
+    Json::object([
+        ("year", year.into()),
+        ("day", day.into()),
+        ("part", res.part.into()),
+        ("impl", res.implementation.as_str().into()),
+        ("input", path.map(|path| path.display().to_string()).into()),
+        ("status", res.status.label().into()),
+        ("answer", match &res.status {
+            Status::Solved(answer) => answer.to_string().into(),
+            _ => Json::Null,
+        }),
+        ("error", res.status.message().into()),
+        ("parse_ns", parse_elapsed.map(|parse| parse.as_nanos()).into()),
+        ("solve_ns", parse_elapsed.map(|_| res.elapsed.as_nanos()).into()),
+    ])
+}
+
+fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+fn run_all(
+    registry: &Registry,
+    year: Option<u16>,
+    days: Option<DayRange>,
+    kind: InputKind,
+    timeout: Option<Duration>,
+    format: Format,
+) {
+    if format == Format::Text {
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {:>10}  {:>10}  Status",
+            "Year", "Day", "Part", "Answer", "Parse", "Solve",
+        );
+    }
+
+    let print = |year: u16, day: u8, path: Option<&Path>, res: &PartResult, parse_elapsed: Option<Duration>| {
+        if format == Format::Json {
+            println!("{}", part_record(year, day, path, res, parse_elapsed));
+            return;
+        }
+
+        let (part, status) = (res.part, &res.status);
+
+        let answer = match status {
+            Status::Solved(answer) => answer.to_string(),
+            // The label says it all, and the reason would not fit.
+            Status::Unavailable(_) => String::new(),
+            _ => status.message().unwrap_or_default(),
+        };
+
+        let (parse, solve) = parse_elapsed
+            .map(|parse| (format_duration(parse), format_duration(res.elapsed)))
+            .unwrap_or_default();
+
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:<20}  {:>10}  {:>10}  {}",
+            year, day, part, answer, parse, solve, status.label(),
+        );
+    };
+
+    let selected = registry.iter()
+        .filter(|&((y, day), _)| {
+            year.is_none_or(|year| year == y) && days.is_none_or(|days| days.contains(day))
+        });
+
+    let mut failed = false;
+
+    for ((y, day), solution) in selected {
+        for (parts, path) in discover_inputs(y, day, &[1, 2], kind) {
+            let path = path.ok();
+
+            match path.as_deref().map(input::read) {
+                Some(Ok(input)) => {
+                    // A sample's expected answers may rely on other parameters.
+                    let params = match (kind, path.as_deref()) {
+                        (InputKind::Sample, Some(path)) => input::sample_params(y, path).unwrap_or_else(|e| exit_with(e)),
+                        _ => Params::default(),
+                    };
+
+                    let run = runner::run_parts(solution, &input, &parts, &RunOptions { params, timeout, ..Default::default() });
+
+                    for res in run.parts {
+                        failed |= matches!(res.status, Status::Failed(_) | Status::Panicked(_) | Status::TimedOut);
+                        print(y, day, path.as_deref(), &res, Some(run.parse_elapsed));
+                    }
+                },
+                _ => {
+                    // A `todo!()` part does not need an input to say so, and
+                    // is worth telling apart from one that just lacks it.
+                    let probe = runner::run_parts(solution, "", &parts, &RunOptions { timeout, ..Default::default() });
 
-    #[arg(short, long, default_value_t = 1)]
-    part: u8,
+                    for res in probe.parts {
+                        match res.status {
+                            Status::NotImplemented => print(y, day, None, &res, None),
+                            _ => print(y, day, None, &no_input(res.part), None),
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn run_bench(registry: &Registry, target: &Target, warmup: usize, iterations: usize, format: Format) {
+    let (year, day) = target.year_day(registry);
+
+    let Some(solution) = registry.get(year, day) else {
+        exit_with(format!("{} day {} not implemented", year, day));
+    };
+
+    target.check_implementation(solution.as_ref());
+
+    let inputs = read_inputs(target, year, day).unwrap_or_else(|e| exit_with(e));
+
+    for TargetInput { parts, path, input } in inputs {
+        // Only parts that solve are measured, and a panic in a `todo!()` one
+        // is caught here rather than in the timed loop.
+        for res in runner::run_parts(solution, &input, &parts, &target.run_options()).parts {
+            if !matches!(res.status, Status::Solved(_)) {
+                exit_with(format!(
+                    "{} day {} part {}: {}",
+                    year, day, res.part, res.status.message().unwrap_or_else(|| res.status.label().to_string()),
+                ));
+            }
+        }
+
+        let parsed = solution.parse_any(&input, &target.params()).unwrap_or_else(|e| exit_with(e));
+
+        for part in parts {
+            let implementation = runner::implementation_for(solution.as_ref(), part, target.implementation());
+
+            let samples = bench::measure(warmup, iterations, || {
+                let _ = std::hint::black_box(
+                    solution.solve_impl(std::hint::black_box(parsed.as_ref()), part, implementation),
+                );
+            });
+
+            let Some(stats) = Stats::from_samples(&samples) else {
+                exit_with("no iterations to measure");
+            };
+
+            if format == Format::Json {
+                println!("{}", Json::object([
+                    ("year", year.into()),
+                    ("day", day.into()),
+                    ("part", part.into()),
+                    ("impl", implementation.into()),
+                    ("input", path.as_ref().map(|path| path.display().to_string()).into()),
+                    ("warmup", warmup.into()),
+                    ("samples", stats.samples.into()),
+                    ("min_ns", stats.min.as_nanos().into()),
+                    ("median_ns", stats.median.as_nanos().into()),
+                    ("mean_ns", stats.mean.as_nanos().into()),
+                    ("p95_ns", stats.p95.as_nanos().into()),
+                    ("stddev_ns", stats.stddev.as_nanos().into()),
+                    ("mild_outliers", stats.mild_outliers.into()),
+                    ("severe_outliers", stats.severe_outliers.into()),
+                ]));
+                continue;
+            }
+
+            println!(
+                "{} day {} part {} ({} iterations after {} warm-up)",
+                year, day, part, stats.samples, warmup,
+            );
+            println!("  min     {:>10}", format_duration(stats.min));
+            println!("  median  {:>10}", format_duration(stats.median));
+            println!("  mean    {:>10}", format_duration(stats.mean));
+            println!("  p95     {:>10}", format_duration(stats.p95));
+            println!("  stddev  {:>10}", format_duration(stats.stddev));
+            println!(
+                "  outliers {} ({} mild, {} severe)",
+                stats.mild_outliers + stats.severe_outliers,
+                stats.mild_outliers,
+                stats.severe_outliers,
+            );
+        }
+    }
+}
+
+fn run_single(registry: &Registry, target: &Target, format: Format) {
+    let (year, day) = target.year_day(registry);
+
+    let Some(solution) = registry.get(year, day) else {
+        exit_with(format!("{} day {} not implemented", year, day));
+    };
+
+    target.check_implementation(solution.as_ref());
+
+    let inputs = read_inputs(target, year, day).unwrap_or_else(|e| exit_with(e));
+
+    if format == Format::Json {
+        let mut ok = true;
+
+        for TargetInput { parts, path, input } in inputs {
+            let run = runner::run_parts(solution, &input, &parts, &target.run_options());
+
+            for res in run.parts {
+                ok &= matches!(res.status, Status::Solved(_));
+                println!(
+                    "{}",
+                    part_record(year, day, path.as_deref(), &res, Some(run.parse_elapsed)),
+                );
+            }
+        }
+
+        if !ok {
+            std::process::exit(1);
+        }
+
+        return;
+    }
+
+    let mut answers: Vec<PartResult> = vec![];
+
+    for TargetInput { parts, input, .. } in inputs {
+        answers.extend(runner::run_parts(solution, &input, &parts, &target.run_options()).parts);
+    }
+
+    let answers = answers.into_iter()
+        .map(|res| match res.status {
+            Status::Solved(answer) => (res.part, answer),
+            status => exit_with(format!(
+                "{} day {} part {}: {}",
+                year, day, res.part, status.message().unwrap_or_else(|| status.label().to_string()),
+            )),
+        })
+        .collect::<Vec<_>>();
+
+    match answers.as_slice() {
+        [(_, res)] => println!("{}", res),
+        _ => answers.iter().for_each(|(part, res)| println!("Part {}: {}", part, res)),
+    }
+}
+
+/// How often `--watch` checks the input files for changes.
+const WATCH_INTERVAL: Duration = Duration::from_millis(200);
+
+fn run_watch(registry: &Registry, target: &Target, format: Format) -> ! {
+    let (year, day) = target.year_day(registry);
+
+    let Some(solution) = registry.get(year, day) else {
+        exit_with(format!("{} day {} not implemented", year, day));
+    };
+
+    target.check_implementation(solution.as_ref());
+
+    // Every candidate is watched, so that creating a more specific input
+    // than the one in use counts as a change too.
+    let paths = match (&target.input, target.kind.input_kind()) {
+        (Some(path), _) => vec![path.clone()],
+        (None, Some(kind)) => target.part.parts().iter()
+            .flat_map(|&part| input::candidates(year, day, part, kind))
+            .unique()
+            .collect(),
+        (None, None) => exit_with("`--watch` needs an input file, see `--input`, `--sample` and `--real`"),
+    };
+
+    let mut watcher = Watcher::new(paths);
+
+    loop {
+        watcher.wait(WATCH_INTERVAL);
+        watch_once(solution, target, year, day, format);
+    }
+}
+
+/// Runs `target` once for `--watch`, reporting errors instead of exiting.
+fn watch_once(solution: &Arc<dyn AnySolution>, target: &Target, year: u16, day: u8, format: Format) {
+    let inputs = match read_inputs(target, year, day) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return;
+        },
+    };
+
+    for TargetInput { parts, path, input } in inputs {
+        let run = runner::run_parts(solution, &input, &parts, &target.run_options());
+
+        if format == Format::Text {
+            println!("--- {}", path.as_deref().map(|path| path.display().to_string()).unwrap_or_default());
+        }
+
+        for res in run.parts {
+            match format {
+                Format::Json => println!("{}", part_record(year, day, path.as_deref(), &res, Some(run.parse_elapsed))),
+                Format::Text => println!(
+                    "Part {}: {}  (parse {}, solve {})",
+                    res.part,
+                    match &res.status {
+                        Status::Solved(answer) => answer.to_string(),
+                        status => status.message().unwrap_or_else(|| status.label().to_string()),
+                    },
+                    format_duration(run.parse_elapsed),
+                    format_duration(res.elapsed),
+                ),
+            }
+        }
+    }
 }
 
-macro_rules! aoc_dispatch {
-    ($year:tt, $day:expr, $part:expr, max_day = $max:tt) => {
-        paste::paste! {
-            seq_macro::seq!(D in 01..=$max {
-                match ($day, $part) {
-                    #((D, 1) => [<y $year>]::day~D::part1(),)*
-                    #((D, 2) => [<y $year>]::day~D::part2(),)*
-                    _ => panic!("Day {} part {} not implemented", $day, $part),
+fn run_accept(registry: &Registry, target: &Target) {
+    let (year, day) = target.year_day(registry);
+
+    // The store is keyed by input only, and `verify` reruns with defaults.
+    if !target.params.is_empty() {
+        exit_with("accepted answers must use the default parameters");
+    }
+
+    // `verify` only ever finds the real inputs again.
+    if target.input.is_some() || target.kind.input_kind() != Some(InputKind::Real) {
+        exit_with("only answers for the real input can be accepted, see `--real`");
+    }
+
+    let Some(solution) = registry.get(year, day) else {
+        exit_with(format!("{} day {} not implemented", year, day));
+    };
+
+    target.check_implementation(solution.as_ref());
+
+    let inputs = read_inputs(target, year, day).unwrap_or_else(|e| exit_with(e));
+
+    let path = Path::new(store::STORE_PATH);
+    let mut store = AnswerStore::load(path).unwrap_or_else(|e| exit_with(e));
+
+    for TargetInput { parts, input, .. } in inputs {
+        let hash = store::input_hash(&input);
+
+        for res in runner::run_parts(solution, &input, &parts, &target.run_options()).parts {
+            match res.status {
+                Status::Solved(answer) => {
+                    store.record(year, day, res.part, &hash, &answer);
+                    println!("Part {}: {} (accepted)", res.part, answer);
+                },
+                status => exit_with(format!(
+                    "{} day {} part {}: {}",
+                    year, day, res.part, status.message().unwrap_or_else(|| status.label().to_string()),
+                )),
+            }
+        }
+    }
+
+    store.save(path).unwrap_or_else(|e| exit_with(e));
+}
+
+fn run_verify(registry: &Registry, timeout: Option<Duration>) {
+    let store = AnswerStore::load(Path::new(store::STORE_PATH)).unwrap_or_else(|e| exit_with(e));
+
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:<20}  Status",
+        "Year", "Day", "Part", "Expected", "Answer",
+    );
+
+    let print = |entry: &Entry, answer: &str, status: &str| println!(
+        "{:>4}  {:>3}  {:>4}  {:<20}  {:<20}  {}",
+        entry.year, entry.day, entry.part, entry.answer, answer, status,
+    );
+
+    let (mut passed, mut failed, mut changed, mut skipped) = (0, 0, 0, 0);
+
+    // Entries of one input are adjacent, so each input is parsed only once.
+    for (_, entries) in &store.entries().chunk_by(|entry| (entry.year, entry.day, entry.hash)) {
+        let entries = entries.collect::<Vec<_>>();
+        let (year, day, hash) = (entries[0].year, entries[0].day, entries[0].hash);
+
+        let Some(solution) = registry.get(year, day) else {
+            entries.iter().for_each(|entry| print(entry, "", Status::NotImplemented.label()));
+            failed += entries.len();
+            continue;
+        };
+
+        let parts = entries.iter().map(|entry| entry.part).collect::<Vec<_>>();
+
+        for (parts, path) in discover_inputs(year, day, &parts, InputKind::Real) {
+            let entries = entries.iter().filter(|entry| parts.contains(&entry.part));
+
+            // An input that changed since it was accepted may well be the
+            // wrong one, unlike one that is not there, e.g., on another machine.
+            let input = match path.and_then(|path| input::read(&path)) {
+                Ok(input) if store::input_hash(&input) == hash => input,
+                Ok(_) => {
+                    entries.for_each(|entry| print(entry, "", "INPUT CHANGED"));
+                    changed += parts.len();
+                    continue;
+                },
+                Err(_) => {
+                    entries.for_each(|entry| print(entry, "", Status::NoInput.label()));
+                    skipped += parts.len();
+                    continue;
+                },
+            };
+
+            let run = runner::run_parts(solution, &input, &parts, &RunOptions { timeout, ..Default::default() });
+
+            for (entry, res) in entries.zip(run.parts) {
+                match res.status {
+                    Status::Solved(answer) if answer.to_string() == entry.answer => {
+                        print(entry, &answer.to_string(), "ok");
+                        passed += 1;
+                    },
+                    Status::Solved(answer) => {
+                        print(entry, &answer.to_string(), "MISMATCH");
+                        failed += 1;
+                    },
+                    status @ Status::Unavailable(_) => {
+                        print(entry, "", status.label());
+                        skipped += 1;
+                    },
+                    status => {
+                        print(entry, "", status.label());
+                        failed += 1;
+                    },
                 }
-            })
+            }
         }
+    }
+
+    println!();
+    println!("{} ok, {} failed, {} input changed, {} skipped", passed, failed, changed, skipped);
+
+    if failed > 0 || changed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn run_crosscheck(registry: &Registry, target: &Target, timeout: Option<Duration>, format: Format) {
+    let (year, day) = target.year_day(registry);
+
+    if target.implementation.is_some() {
+        exit_with("`crosscheck` runs every implementation, so `--impl` makes no sense");
+    }
+
+    let Some(solution) = registry.get(year, day) else {
+        exit_with(format!("{} day {} not implemented", year, day));
     };
+
+    let inputs = read_inputs(target, year, day).unwrap_or_else(|e| exit_with(e));
+    let options = RunOptions { timeout, ..target.run_options() };
+
+    if format == Format::Text {
+        println!("{:>4}  {:<12}  {:<20}  {:>10}  Status", "Part", "Impl", "Answer", "Solve");
+    }
+
+    let mut ok = true;
+
+    for TargetInput { parts, path, input } in inputs {
+        let run = runner::crosscheck(solution, &input, &parts, &options);
+
+        for res in &run.parts {
+            // The default implementation comes first for each part.
+            let expected = run.parts.iter()
+                .find(|other| other.part == res.part)
+                .map(|default| &default.status);
+
+            let status = match &res.status {
+                Status::Solved(_) if Some(&res.status) == expected => "ok",
+                Status::Solved(_) => "MISMATCH",
+                status => status.label(),
+            };
+
+            ok &= status == "ok";
+
+            match format {
+                Format::Json => {
+                    // The record's `status` is `ok` even if the answer disagrees.
+                    let mut record = part_record(year, day, path.as_deref(), res, Some(run.parse_elapsed));
+
+                    if let Json::Object(fields) = &mut record {
+                        fields.push(("crosscheck".to_string(), status.into()));
+                    }
+
+                    println!("{}", record);
+                },
+                Format::Text => println!(
+                    "{:>4}  {:<12}  {:<20}  {:>10}  {}",
+                    res.part,
+                    res.implementation,
+                    match &res.status {
+                        Status::Solved(answer) => answer.to_string(),
+                        status => status.message().unwrap_or_default(),
+                    },
+                    format_duration(res.elapsed),
+                    status,
+                ),
+            }
+        }
+    }
+
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn list_params(registry: &Registry, year: Option<u16>, day: Option<u8>) {
+    println!("{:>4}  {:>3}  {:<12}  {:<6}  {:>8}  Description", "Year", "Day", "Name", "Type", "Default");
+
+    let selected = registry.iter()
+        .filter(|&((y, d), _)| year.is_none_or(|year| year == y) && day.is_none_or(|day| day == d));
+
+    for ((y, d), solution) in selected {
+        for param in solution.params() {
+            println!(
+                "{:>4}  {:>3}  {:<12}  {:<6}  {:>8}  {}",
+                y, d, param.name, param.ty, param.default, param.help,
+            );
+        }
+    }
+}
+
+fn exit_with(msg: impl std::fmt::Display) -> ! {
+    eprintln!("error: {}", msg);
+    std::process::exit(1);
 }
 
 fn main() {
     let args = Args::parse();
+    let registry = Registry::all();
 
-    let res = match args.year {
-        2025 => aoc_dispatch!(2025, args.day, args.part, max_day = 12),
-        _ => panic!("Year {} not implemented", args.year),
-    };
+    if args.verbose > 0 && !cfg!(feature = "trace") {
+        eprintln!("warning: built without the `trace` feature, so `-v` shows nothing");
+    }
+
+    trace::set_level(match args.verbose {
+        0 => None,
+        1 => Some(Level::Debug),
+        _ => Some(Level::Trace),
+    });
+
+    match &args.command {
+        Some(Command::All { year, days, kind, timeout }) => {
+            run_all(&registry, *year, *days, kind.input_kind().unwrap_or(InputKind::Real), *timeout, args.format);
+        },
+        Some(Command::Bench { target, warmup, iterations }) => {
+            run_bench(&registry, target, *warmup, *iterations, args.format);
+        },
+        Some(Command::Accept { target }) => run_accept(&registry, target),
+        Some(Command::Verify { timeout }) => run_verify(&registry, *timeout),
+        Some(Command::Crosscheck { target, timeout }) => run_crosscheck(&registry, target, *timeout, args.format),
+        Some(Command::ListParams { year, day }) => list_params(&registry, *year, *day),
+        Some(Command::New { year, day }) => {
+            let year = latest_year(&registry, *year);
+            let touched = scaffold::new_day(Path::new("."), year, *day).unwrap_or_else(|e| exit_with(e));
 
-    println!("{}", res);
+            for path in touched {
+                println!("wrote {}", path.display());
+            }
+        },
+        None if args.watch => run_watch(&registry, &args.target, args.format),
+        None => run_single(&registry, &args.target, args.format),
+    }
 }
@@ -0,0 +1,204 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::error::{AocError, Result};
+
+/// Answers keyed by `[section]` and then by `key = value`, e.g.,
+///
+/// ```toml
+/// [day01_sample]
+/// part1 = 3
+/// part2 = "6"
+/// ```
+///
+/// Only this small subset of TOML is understood: comments, bare integers and
+/// basic strings with `\n`, `\"` and `\\` escapes. The build script includes
+/// this module too, so it must stay std-only.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Manifest {
+    sections: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl Manifest {
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut sections: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        let mut current = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let err = |reason: &str| AocError::new(reason).with_line(i + 1);
+
+            if let Some(section) = line.strip_prefix('[') {
+                let section = section.strip_suffix(']')
+                    .ok_or_else(|| err("unterminated section header"))?
+                    .trim();
+
+                sections.entry(section.to_string()).or_default();
+                current = Some(section.to_string());
+                continue;
+            }
+
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| err("expected `key = value`"))?;
+
+            let section = current.as_ref()
+                .ok_or_else(|| err("entry outside of a section"))?;
+
+            let value = parse_value(value.trim()).ok_or_else(|| err("expected an integer or a string"))?;
+
+            sections.get_mut(section)
+                .unwrap()
+                .insert(key.trim().to_string(), value);
+        }
+
+        Ok(Self { sections })
+    }
+
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.sections.get(section)?
+            .get(key)
+            .map(String::as_str)
+    }
+
+    pub fn set(&mut self, section: &str, key: &str, value: impl Into<String>) {
+        self.sections.entry(section.to_string())
+            .or_default()
+            .insert(key.to_string(), value.into());
+    }
+
+    /// Removes `key` from `section`, and the section once it is empty.
+    pub fn remove(&mut self, section: &str, key: &str) -> Option<String> {
+        let entries = self.sections.get_mut(section)?;
+        let value = entries.remove(key);
+
+        if entries.is_empty() {
+            self.sections.remove(section);
+        }
+
+        value
+    }
+
+    pub fn sections(&self) -> impl Iterator<Item = (&str, &BTreeMap<String, String>)> {
+        self.sections.iter()
+            .map(|(section, entries)| (section.as_str(), entries))
+    }
+}
+
+impl fmt::Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (section, entries)) in self.sections.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "[{}]", section)?;
+
+            for (key, value) in entries {
+                writeln!(f, "{} = {}", key, format_value(value))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_value(value: &str) -> String {
+    match value.parse::<i128>() {
+        Ok(n) if n.to_string() == value => value.to_string(),
+        _ => format!(
+            "\"{}\"",
+            value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"),
+        ),
+    }
+}
+
+fn parse_value(s: &str) -> Option<String> {
+    let Some(quoted) = s.strip_prefix('"') else {
+        return s.parse::<i128>().ok().map(|n| n.to_string());
+    };
+
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+
+    loop {
+        match chars.next()? {
+            '"' => break,
+            '\\' => value.push(match chars.next()? {
+                'n' => '\n',
+                '"' => '"',
+                '\\' => '\\',
+                _ => return None,
+            }),
+            c => value.push(c),
+        }
+    }
+
+    // Nothing but a comment may follow the closing quote.
+    match chars.as_str().trim() {
+        rest if rest.is_empty() || rest.starts_with('#') => Some(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::error::AocError;
+    use crate::manifest::Manifest;
+
+    #[test]
+    fn test_parse() {
+        let manifest = Manifest::parse(
+            "# expected answers\n\
+             [day01_sample]\n\
+             part1 = 3\n\
+             part2 = \"a\\\"b\\nc\" # trailing comment\n\
+             \n\
+             [day11_sample_part2]\n\
+             part2 = -2\n",
+        ).unwrap();
+
+        assert_eq!(manifest.get("day01_sample", "part1"), Some("3"));
+        assert_eq!(manifest.get("day01_sample", "part2"), Some("a\"b\nc"));
+        assert_eq!(manifest.get("day11_sample_part2", "part2"), Some("-2"));
+        assert_eq!(manifest.get("day11_sample_part2", "part1"), None);
+        assert_eq!(manifest.sections().count(), 2);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut manifest = Manifest::default();
+
+        manifest.set("day01_sample", "part1", "3");
+        manifest.set("day01_sample", "part2", "0012");
+        manifest.set("day02_sample", "part1", "a \"b\"\nc\\");
+
+        assert_eq!(
+            manifest.to_string(),
+            "[day01_sample]\n\
+             part1 = 3\n\
+             part2 = \"0012\"\n\
+             \n\
+             [day02_sample]\n\
+             part1 = \"a \\\"b\\\"\\nc\\\\\"\n",
+        );
+        assert_eq!(Manifest::parse(&manifest.to_string()), Ok(manifest));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Manifest::parse("part1 = 3"),
+            Err(AocError::new("entry outside of a section").with_line(1)),
+        );
+        assert_eq!(
+            Manifest::parse("[day01_sample]\npart1 = three"),
+            Err(AocError::new("expected an integer or a string").with_line(2)),
+        );
+        assert!(Manifest::parse("[day01_sample\n").is_err());
+        assert!(Manifest::parse("[a]\nb = \"unterminated\n").is_err());
+    }
+}
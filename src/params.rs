@@ -0,0 +1,111 @@
+use std::{any::type_name, collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::error::{AocError, Result};
+
+/// Values passed with `--param key=value`. Anything not given falls back to
+/// the default of the corresponding [`Param`].
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new(pairs: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            values: pairs.into_iter().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Fails on the first key that is not in `accepted`.
+    pub fn check(&self, accepted: &[ParamInfo]) -> Result<()> {
+        match self.values.keys().find(|key| !accepted.iter().any(|param| param.name == *key)) {
+            Some(key) => Err(AocError::new(format!(
+                "unknown parameter `{}` (accepted: {})",
+                key,
+                match accepted {
+                    [] => "none".to_string(),
+                    _ => accepted.iter().map(|param| param.name).collect::<Vec<_>>().join(", "),
+                },
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Splits `key=value`, e.g., for parsing command-line arguments.
+pub fn parse_pair(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => Ok((key.trim().to_string(), value.trim().to_string())),
+        _ => Err(format!("expected `key=value`, found `{}`", s)),
+    }
+}
+
+/// A typed parameter that a solution declares, usually as a `const`.
+pub struct Param<T> {
+    pub name: &'static str,
+    pub default: T,
+    pub help: &'static str,
+}
+
+impl<T: FromStr + Display + Copy> Param<T> {
+    pub const fn new(name: &'static str, default: T, help: &'static str) -> Self {
+        Self { name, default, help }
+    }
+
+    /// The value given in `params`, or the default.
+    pub fn get(&self, params: &Params) -> Result<T> {
+        match params.values.get(self.name) {
+            Some(value) => value.parse().map_err(|_| AocError::new(format!(
+                "invalid value `{}` for parameter `{}`, expected {}",
+                value,
+                self.name,
+                type_name::<T>(),
+            ))),
+            None => Ok(self.default),
+        }
+    }
+
+    pub fn info(&self) -> ParamInfo {
+        ParamInfo {
+            name: self.name,
+            ty: type_name::<T>(),
+            default: self.default.to_string(),
+            help: self.help,
+        }
+    }
+}
+
+/// A [`Param`] with its type erased, for listing.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParamInfo {
+    pub name: &'static str,
+    pub ty: &'static str,
+    pub default: String,
+    pub help: &'static str,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::params::{Param, Params, parse_pair};
+
+    const SIZE: Param<u32> = Param::new("size", 100, "number of positions");
+
+    #[test]
+    fn test_param() {
+        let params = Params::new([parse_pair("size=10").unwrap()]);
+
+        assert_eq!(SIZE.get(&Params::default()), Ok(100));
+        assert_eq!(SIZE.get(&params), Ok(10));
+        assert!(SIZE.get(&Params::new([parse_pair("size=-1").unwrap()])).is_err());
+
+        assert_eq!(params.check(&[SIZE.info()]), Ok(()));
+        assert!(Params::new([parse_pair("start=1").unwrap()]).check(&[SIZE.info()]).is_err());
+
+        assert!(parse_pair("size").is_err());
+        assert!(parse_pair("=1").is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::solution::{AnySolution, Solution};
+
+/// Maps `(year, day)` to its solution. Solutions are shared so that a run can
+/// hand them to a watchdog thread.
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Arc<dyn AnySolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self {
+            solutions: BTreeMap::new(),
+        }
+    }
+
+    /// Every solution in this crate.
+    pub fn all() -> Self {
+        let mut registry = Self::new();
+
+        crate::register_days(&mut registry);
+
+        registry
+    }
+
+    pub fn register<S>(&mut self, year: u16, day: u8, solution: S)
+        where S: Solution + Send + Sync + 'static,
+              S::Input: Send + Sync + 'static,
+    {
+        self.solutions.insert((year, day), Arc::new(solution));
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Arc<dyn AnySolution>> {
+        self.solutions.get(&(year, day))
+    }
+
+    /// Iterates over `(year, day)` in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = ((u16, u8), &Arc<dyn AnySolution>)> {
+        self.solutions.iter().map(|(&key, solution)| (key, solution))
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years = self.solutions.keys().map(|&(year, _)| year).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::answer::Answer;
+    use crate::error::{AocError, Result, parse_lines};
+    use crate::params::Params;
+    use crate::registry::Registry;
+    use crate::solution::Solution;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            parse_lines(input, |line| line.parse().map_err(|_| AocError::new("not a number")))
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().product::<i64>().into())
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::new();
+
+        registry.register(2024, 2, Sum);
+        registry.register(2024, 1, Sum);
+        registry.register(2023, 7, Sum);
+
+        assert_eq!(
+            registry.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+            vec![(2023, 7), (2024, 1), (2024, 2)],
+        );
+        assert_eq!(registry.years(), vec![2023, 2024]);
+        assert!(registry.get(2024, 3).is_none());
+
+        let solution = registry.get(2024, 1).unwrap();
+        let parsed = solution.parse_any("2\n3\n4", &Params::default()).unwrap();
+
+        assert_eq!(solution.solve_any(parsed.as_ref(), 1), Ok(Answer::Int(9)));
+        assert_eq!(solution.solve_any(parsed.as_ref(), 2), Ok(Answer::Int(24)));
+        assert!(solution.solve_any(parsed.as_ref(), 3).is_err());
+    }
+}
@@ -0,0 +1,370 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Once, mpsc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::answer::Answer;
+use crate::error::AocError;
+use crate::params::Params;
+use crate::solution::{AnySolution, DEFAULT_IMPL};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Status {
+    Solved(Answer),
+    Failed(AocError),
+    NoInput,
+    NotImplemented,
+    /// Why this build cannot solve the part, see `AocError::unavailable`.
+    Unavailable(String),
+    /// The panic message, followed by where it was raised.
+    Panicked(String),
+    TimedOut,
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Solved(_) => "ok",
+            Status::Failed(_) => "error",
+            Status::NoInput => "no input",
+            Status::NotImplemented => "not implemented",
+            Status::Unavailable(_) => "unavailable",
+            Status::Panicked(_) => "panicked",
+            Status::TimedOut => "timed out",
+        }
+    }
+
+    /// Why the part did not produce an answer, if there is more to say than
+    /// the label.
+    pub fn message(&self) -> Option<String> {
+        match self {
+            Status::Failed(e) => Some(e.to_string()),
+            Status::Unavailable(reason) => Some(reason.clone()),
+            Status::Panicked(msg) => Some(msg.clone()),
+            _ => None,
+        }
+    }
+}
+
+pub struct PartResult {
+    pub part: u8,
+    /// Which implementation of the part ran, see `AnySolution::impls`.
+    pub implementation: String,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub params: Params,
+    /// With a timeout, parsing and each part run on a separate thread, and
+    /// one that takes longer is reported as `TimedOut`. Threads cannot be
+    /// killed, so it keeps running in the background until the process exits.
+    pub timeout: Option<Duration>,
+    /// Run this implementation instead of the default one, see
+    /// `implementation_for`.
+    pub implementation: Option<String>,
+}
+
+/// Parses `input` once and runs the requested `parts` against it, catching
+/// panics so that a `todo!()` day does not take down the whole run.
+pub fn run_parts(
+    solution: &Arc<dyn AnySolution>,
+    input: &str,
+    parts: &[u8],
+    options: &RunOptions,
+) -> Run {
+    let implementation = options.implementation.as_deref().unwrap_or(DEFAULT_IMPL);
+
+    let jobs = parts.iter()
+        .map(|&part| (part, implementation_for(solution.as_ref(), part, implementation).to_string()))
+        .collect();
+
+    run_jobs(solution, input, jobs, options)
+}
+
+/// The implementation of `part` that runs when `name` is asked for. A part
+/// without it runs the default one instead, as long as the other part has
+/// it, so that an unknown name still fails with the available ones.
+pub fn implementation_for<'a>(solution: &dyn AnySolution, part: u8, name: &'a str) -> &'a str {
+    let has = |part: u8| solution.impls(part).contains(&name);
+
+    if !has(part) && (1..=2).any(has) {
+        DEFAULT_IMPL
+    } else {
+        name
+    }
+}
+
+/// Like `run_parts`, but runs every implementation of each part, so that
+/// their answers can be compared.
+pub fn crosscheck(
+    solution: &Arc<dyn AnySolution>,
+    input: &str,
+    parts: &[u8],
+    options: &RunOptions,
+) -> Run {
+    let jobs = parts.iter()
+        .flat_map(|&part| solution.impls(part).into_iter().map(move |name| (part, name.to_string())))
+        .collect();
+
+    run_jobs(solution, input, jobs, options)
+}
+
+fn run_jobs(
+    solution: &Arc<dyn AnySolution>,
+    input: &str,
+    jobs: Vec<(u8, String)>,
+    options: &RunOptions,
+) -> Run {
+    let (input, params) = (input.to_string(), options.params.clone());
+
+    let parser = Arc::clone(solution);
+    let (parsed, parse_elapsed) = isolate(options.timeout, move || parser.parse_any(&input, &params));
+    let parsed = parsed
+        .and_then(|parsed| parsed.map_err(Status::Failed))
+        .map(Arc::new);
+
+    let parts = jobs.into_iter()
+        .map(|(part, implementation)| {
+            let (status, elapsed) = match &parsed {
+                Ok(parsed) => {
+                    let (solution, parsed, name) = (Arc::clone(solution), Arc::clone(parsed), implementation.clone());
+                    let (res, elapsed) = isolate(options.timeout, move || {
+                        solution.solve_impl(parsed.as_ref().as_ref(), part, &name)
+                    });
+
+                    let status = match res {
+                        Ok(Ok(res)) => Status::Solved(res),
+                        Ok(Err(e)) if e.unavailable => Status::Unavailable(e.to_string()),
+                        Ok(Err(e)) => Status::Failed(e),
+                        Err(status) => status,
+                    };
+
+                    (status, elapsed)
+                },
+                Err(status) => (status.clone(), Duration::ZERO),
+            };
+
+            PartResult {
+                part,
+                implementation,
+                status,
+                elapsed,
+            }
+        })
+        .collect();
+
+    Run {
+        parse_elapsed,
+        parts,
+    }
+}
+
+/// Runs `f` through `catch`, on a watchdog thread if there is a `timeout`.
+/// Returns how long `f` took, or the `timeout` if it did not finish.
+fn isolate<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> (Result<T, Status>, Duration) {
+    let timed = move || {
+        let start = Instant::now();
+        let res = catch(f);
+        (res, start.elapsed())
+    };
+
+    let Some(timeout) = timeout else {
+        return timed();
+    };
+
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        // Nobody is listening any more if we timed out.
+        let _ = tx.send(timed());
+    });
+
+    rx.recv_timeout(timeout)
+        .unwrap_or((Err(Status::TimedOut), timeout))
+}
+
+thread_local! {
+    /// Whether this thread is inside `catch`, whose panics are reported
+    /// through `Status` rather than by the panic hook.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+
+    /// Where the last panic caught on this thread was raised.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, turning a panic into a `Status`. Panics of `todo!()` become
+/// `NotImplemented`; any other becomes `Panicked` with its message.
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Status> {
+    static INSTALL_HOOK: Once = Once::new();
+
+    INSTALL_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                LOCATION.set(info.location().map(|location| location.to_string()));
+            } else {
+                hook(info);
+            }
+        }));
+    });
+
+    let catching = CATCHING.replace(true);
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+
+    res.map_err(|payload| match panic_message(payload.as_ref()) {
+        Some(msg) if is_not_implemented(msg) => Status::NotImplemented,
+        msg => {
+            let msg = msg.unwrap_or("non-string panic payload");
+
+            match LOCATION.take() {
+                Some(location) => Status::Panicked(format!("{} at {}", msg, location)),
+                None => Status::Panicked(msg.to_string()),
+            }
+        },
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+}
+
+fn is_not_implemented(msg: &str) -> bool {
+    // `todo!()` and `unimplemented!()` panic with these messages.
+    msg.starts_with("not yet implemented") || msg.starts_with("not implemented")
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, time::Duration};
+
+    use crate::answer::Answer;
+    use crate::error::{AocError, Result, parse_lines};
+    use crate::runner::{RunOptions, Status, crosscheck, run_parts};
+    use crate::solution::{Alternative, AnySolution, Solution};
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        type Input = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            parse_lines(input, |line| line.parse().map_err(|_| AocError::new("not a number")))
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+            todo!()
+        }
+
+        fn alternatives(&self) -> Vec<Alternative<Self::Input>> {
+            vec![
+                Alternative { name: "count", part: 1, solve: |input| Ok(input.len().into()) },
+                Alternative { name: "lp", part: 2, solve: |_| Err(AocError::unavailable("no LP solver")) },
+            ]
+        }
+    }
+
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input = ();
+
+        fn parse(&self, _input: &str) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &Self::Input) -> Result<Answer> {
+            panic!("boom")
+        }
+
+        fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+            loop {
+                std::thread::sleep(Duration::from_secs(1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_parts() {
+        let unfinished: Arc<dyn AnySolution> = Arc::new(Unfinished);
+
+        let run = run_parts(&unfinished, "1\n2\n3", &[1, 2], &RunOptions::default());
+
+        assert_eq!(run.parts[0].status, Status::Solved(Answer::Int(6)));
+        assert_eq!(run.parts[1].status, Status::NotImplemented);
+
+        let run = run_parts(&unfinished, "1\nx\n3", &[1], &RunOptions::default());
+
+        assert_eq!(
+            run.parts[0].status,
+            Status::Failed(AocError::new("not a number").with_line(2)),
+        );
+    }
+
+    #[test]
+    fn test_run_parts_isolated() {
+        let broken: Arc<dyn AnySolution> = Arc::new(Broken);
+
+        let run = run_parts(&broken, "", &[1, 2], &RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            &run.parts[0].status,
+            Status::Panicked(msg) if msg.starts_with("boom at src/runner.rs:"),
+        ));
+        assert_eq!(run.parts[1].status, Status::TimedOut);
+    }
+
+    #[test]
+    fn test_crosscheck() {
+        let unfinished: Arc<dyn AnySolution> = Arc::new(Unfinished);
+
+        let run = crosscheck(&unfinished, "1\n2\n3", &[1, 2], &RunOptions::default());
+
+        assert_eq!(
+            run.parts.iter()
+                .map(|res| (res.part, res.implementation.as_str(), res.status.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "default", Status::Solved(Answer::Int(6))),
+                (1, "count", Status::Solved(Answer::UInt(3))),
+                (2, "default", Status::NotImplemented),
+                (2, "lp", Status::Unavailable("unavailable in this build, no LP solver".to_string())),
+            ],
+        );
+
+        let options = |name: &str| RunOptions { implementation: Some(name.to_string()), ..Default::default() };
+
+        let run = run_parts(&unfinished, "1\n2\n3", &[1, 2], &options("count"));
+        assert_eq!(run.parts[0].status, Status::Solved(Answer::UInt(3)));
+        assert_eq!((run.parts[1].implementation.as_str(), &run.parts[1].status), ("default", &Status::NotImplemented));
+
+        let run = run_parts(&unfinished, "1\n2\n3", &[1], &options("sum"));
+        assert_eq!(
+            run.parts[0].status,
+            Status::Failed(AocError::new("part 1 has no implementation `sum` (available: default, count)")),
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// Creates a solver stub for `(year, day)` under `root`, along with an empty
+/// sample input and a placeholder in `answers.toml`. `build.rs` picks up the
+/// stub by its file name, and a new year gets a default feature in
+/// `Cargo.toml`. Returns every file created or modified. Refuses to touch an
+/// existing day.
+pub fn new_day(root: &Path, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(ErrorKind::InvalidInput, format!("invalid day {}", day)));
+    }
+
+    let src = root.join("src").join(format!("y{}", year));
+    let module = src.join(format!("day{:02}.rs", day));
+
+    if module.exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} day {} already exists", year, day),
+        ));
+    }
+
+    let mut touched = vec![];
+
+    fs::create_dir_all(&src)?;
+    fs::write(&module, day_template(day))?;
+    touched.push(module);
+
+    let cargo_toml = root.join("Cargo.toml");
+    let contents = fs::read_to_string(&cargo_toml)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", cargo_toml.display(), e)))?;
+
+    if let Some(contents) = add_year_feature(&contents, year)
+        .map_err(|e| io::Error::new(ErrorKind::InvalidData, format!("{}: {}", cargo_toml.display(), e)))? {
+        fs::write(&cargo_toml, contents)?;
+        touched.push(cargo_toml);
+    }
+
+    let inputs = root.join("inputs").join(year.to_string());
+    fs::create_dir_all(&inputs)?;
+
+    let sample = inputs.join(format!("day{:02}_sample.txt", day));
+    if !sample.exists() {
+        fs::write(&sample, "")?;
+        touched.push(sample);
+    }
+
+    let answers = inputs.join("answers.toml");
+    let mut contents = fs::read_to_string(&answers).unwrap_or_else(|_| {
+        "# Expected answers for the sample inputs, keyed by the sample file's stem.\n".to_string()
+    });
+
+    let section = format!("[day{:02}_sample]", day);
+    if !contents.lines().any(|line| line.trim() == section) {
+        contents.push_str(&format!("\n{}\n# part1 = \n# part2 = \n", section));
+        fs::write(&answers, contents)?;
+        touched.push(answers);
+    }
+
+    Ok(touched)
+}
+
+/// Declares the `y<year>` feature in the contents of `Cargo.toml` and enables
+/// it by default, or returns `None` if it is already declared.
+fn add_year_feature(cargo_toml: &str, year: u16) -> Result<Option<String>, &'static str> {
+    let feature = format!("y{}", year);
+    let mut lines = cargo_toml.lines().map(String::from).collect::<Vec<_>>();
+
+    let is_year = |line: &str| line.starts_with('y') && line.ends_with(" = []");
+
+    if lines.iter().any(|line| is_year(line) && line.starts_with(&format!("{} ", feature))) {
+        return Ok(None);
+    }
+
+    let default = lines.iter()
+        .position(|line| line.starts_with("default = ["))
+        .ok_or("no `default` features")?;
+
+    let mut features = lines[default]
+        .strip_prefix("default = [")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or("`default` features do not fit on one line")?
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+
+    features.push(format!("{:?}", feature));
+    lines[default] = format!("default = [{}]", features.join(", "));
+
+    // Keep the year features sorted, after any comment above the first one.
+    let years = lines.iter().enumerate().filter(|(_, line)| is_year(line)).map(|(i, _)| i).collect::<Vec<_>>();
+    let at = match years.iter().find(|&&i| lines[i].as_str() > feature.as_str()) {
+        Some(&i) => i,
+        None => years.last().map_or(default + 1, |&i| i + 1),
+    };
+
+    lines.insert(at, format!("{} = []", feature));
+
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+fn day_template(day: u8) -> String {
+    format!(
+        "\
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::Solution;
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    fn part1(&self, _input: &Self::Input) -> Result<Answer> {{
+        todo!()
+    }}
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {{
+        todo!()
+    }}
+}}
+"
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, io::ErrorKind};
+
+    use crate::scaffold::new_day;
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc25-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join("src/y2025")).unwrap();
+        fs::write(root.join("src/y2025/day03.rs"), "").unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[features]\ndefault = [\"lp\", \"y2025\"]\n# Years.\ny2025 = []\nlp = []\n",
+        ).unwrap();
+
+        new_day(&root, 2025, 2).unwrap();
+
+        assert!(fs::read_to_string(root.join("src/y2025/day02.rs")).unwrap().contains("pub struct Day02;"));
+        assert!(root.join("inputs/2025/day02_sample.txt").is_file());
+        assert!(fs::read_to_string(root.join("inputs/2025/answers.toml")).unwrap().contains("[day02_sample]"));
+
+        assert_eq!(new_day(&root, 2025, 2).unwrap_err().kind(), ErrorKind::AlreadyExists);
+        assert_eq!(new_day(&root, 2025, 3).unwrap_err().kind(), ErrorKind::AlreadyExists);
+        assert_eq!(new_day(&root, 2025, 26).unwrap_err().kind(), ErrorKind::InvalidInput);
+
+        new_day(&root, 2024, 1).unwrap();
+
+        assert!(root.join("src/y2024/day01.rs").is_file());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[features]\ndefault = [\"lp\", \"y2025\", \"y2024\"]\n# Years.\ny2024 = []\ny2025 = []\nlp = []\n",
+        );
+        assert!(fs::read_to_string(root.join("inputs/2024/answers.toml")).unwrap().contains("[day01_sample]"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
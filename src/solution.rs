@@ -0,0 +1,113 @@
+use std::any::Any;
+
+use crate::answer::Answer;
+use crate::error::{AocError, Result};
+use crate::params::{ParamInfo, Params};
+
+/// Name of `part1` and `part2` among a part's implementations.
+pub const DEFAULT_IMPL: &str = "default";
+
+/// Another implementation of `part`, e.g., a naive one to check an optimised
+/// one against.
+pub struct Alternative<I> {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&I) -> Result<Answer>,
+}
+
+/// A puzzle solver for one day. The input is parsed once and then shared by
+/// both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    /// Parameters that `parse_with` accepts, e.g., sizes that differ between
+    /// the sample and the real input.
+    fn params(&self) -> Vec<ParamInfo> {
+        Vec::new()
+    }
+
+    /// Like `parse`, with `params` overriding the defaults of `params()`.
+    /// Solutions with parameters carry their values in `Input`.
+    fn parse_with(&self, input: &str, _params: &Params) -> Result<Self::Input> {
+        self.parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// Implementations besides `part1` and `part2`, selected with `--impl`.
+    fn alternatives(&self) -> Vec<Alternative<Self::Input>> {
+        Vec::new()
+    }
+}
+
+/// Object-safe view of a [`Solution`], with the parsed input type erased so
+/// that solutions for different days can live side by side in a registry.
+/// Both may be sent to another thread, so that a runaway part can be timed out.
+pub trait AnySolution: Send + Sync {
+    fn params(&self) -> Vec<ParamInfo>;
+
+    /// Fails if `params` has a key that the solution does not declare.
+    fn parse_any(&self, input: &str, params: &Params) -> Result<Box<dyn Any + Send + Sync>>;
+
+    /// Names of the implementations of `part`, starting with `DEFAULT_IMPL`.
+    fn impls(&self, part: u8) -> Vec<&'static str>;
+
+    /// Runs implementation `name` of `part` against the value returned by
+    /// `parse_any`.
+    fn solve_impl(&self, parsed: &dyn Any, part: u8, name: &str) -> Result<Answer>;
+
+    fn solve_any(&self, parsed: &dyn Any, part: u8) -> Result<Answer> {
+        self.solve_impl(parsed, part, DEFAULT_IMPL)
+    }
+}
+
+impl<S> AnySolution for S
+    where S: Solution + Send + Sync,
+          S::Input: Send + Sync + 'static,
+{
+    fn params(&self) -> Vec<ParamInfo> {
+        Solution::params(self)
+    }
+
+    fn parse_any(&self, input: &str, params: &Params) -> Result<Box<dyn Any + Send + Sync>> {
+        params.check(&Solution::params(self))?;
+
+        Ok(Box::new(self.parse_with(input, params)?))
+    }
+
+    fn impls(&self, part: u8) -> Vec<&'static str> {
+        if !(1..=2).contains(&part) {
+            return Vec::new();
+        }
+
+        std::iter::once(DEFAULT_IMPL)
+            .chain(self.alternatives().into_iter().filter(|alt| alt.part == part).map(|alt| alt.name))
+            .collect()
+    }
+
+    fn solve_impl(&self, parsed: &dyn Any, part: u8, name: &str) -> Result<Answer> {
+        let parsed = parsed.downcast_ref::<S::Input>()
+            .expect("parsed input belongs to another solution");
+
+        match (part, name) {
+            (1, DEFAULT_IMPL) => return self.part1(parsed),
+            (2, DEFAULT_IMPL) => return self.part2(parsed),
+            (1..=2, _) => {},
+            _ => return Err(AocError::new(format!("part {} does not exist", part))),
+        }
+
+        match self.alternatives().into_iter().find(|alt| alt.part == part && alt.name == name) {
+            Some(alt) => (alt.solve)(parsed),
+            None => Err(AocError::new(format!(
+                "part {} has no implementation `{}` (available: {})",
+                part,
+                name,
+                AnySolution::impls(self, part).join(", "),
+            ))),
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+use crate::answer::Answer;
+use crate::manifest::Manifest;
+
+/// Where accepted answers for the real inputs are recorded.
+pub const STORE_PATH: &str = "inputs/accepted.toml";
+
+/// An accepted answer for one part of one input.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Entry<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub hash: &'a str,
+    pub part: u8,
+    pub answer: &'a str,
+}
+
+/// Accepted answers keyed by year, day, input hash and part, stored as a
+/// manifest with one `[<year>.day<NN>.<hash>]` section per input.
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    manifest: Manifest,
+}
+
+impl AnswerStore {
+    /// Loads the store at `path`, or an empty one if it does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+        };
+
+        let manifest = Manifest::parse(&contents)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))?;
+
+        Ok(Self { manifest })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.manifest.to_string())
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8, hash: &str) -> Option<&str> {
+        self.manifest.get(&section(year, day, hash), &format!("part{}", part))
+    }
+
+    /// Accepts `answer` for `part` of the input with `hash`. It replaces the
+    /// answer accepted for any other input of the day, which is stale now.
+    pub fn record(&mut self, year: u16, day: u8, part: u8, hash: &str, answer: &Answer) {
+        let (prefix, key) = (section(year, day, ""), format!("part{}", part));
+
+        let stale = self.manifest.sections()
+            .filter(|(name, answers)| name.starts_with(&prefix) && answers.contains_key(&key))
+            .map(|(name, _)| name.to_string())
+            .collect::<Vec<_>>();
+
+        for name in stale {
+            self.manifest.remove(&name, &key);
+        }
+
+        self.manifest.set(&section(year, day, hash), &key, answer.to_string());
+    }
+
+    /// Every accepted answer, skipping sections and keys it does not recognise.
+    pub fn entries(&self) -> impl Iterator<Item = Entry<'_>> {
+        self.manifest.sections()
+            .filter_map(|(section, answers)| {
+                let mut it = section.splitn(3, '.');
+                let year = it.next()?.parse().ok()?;
+                let day = it.next()?.strip_prefix("day")?.parse().ok()?;
+                let hash = it.next()?;
+
+                Some(answers.iter().filter_map(move |(key, answer)| {
+                    Some(Entry {
+                        year,
+                        day,
+                        hash,
+                        part: key.strip_prefix("part")?.parse().ok()?,
+                        answer,
+                    })
+                }))
+            })
+            .flatten()
+    }
+}
+
+fn section(year: u16, day: u8, hash: &str) -> String {
+    format!("{}.day{:02}.{}", year, day, hash)
+}
+
+/// 64-bit FNV-1a of `input`, as 16 hex digits. Unlike `DefaultHasher`, it is
+/// stable across Rust releases, so it can be written to disk.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3));
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::answer::Answer;
+    use crate::store::{AnswerStore, Entry, input_hash};
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_record() {
+        let mut store = AnswerStore::default();
+
+        store.record(2025, 1, 2, "abc", &Answer::Int(6));
+        store.record(2025, 1, 1, "abc", &Answer::Int(3));
+        store.record(2025, 11, 1, "def", &Answer::Text("x".to_string()));
+
+        assert_eq!(store.get(2025, 1, 1, "abc"), Some("3"));
+        assert_eq!(store.get(2025, 1, 1, "def"), None);
+
+        // A new input for a part replaces the old one.
+        store.record(2025, 11, 1, "ghi", &Answer::Int(4));
+
+        assert_eq!(store.get(2025, 11, 1, "def"), None);
+
+        assert_eq!(
+            store.entries().collect::<Vec<_>>(),
+            vec![
+                Entry { year: 2025, day: 1, hash: "abc", part: 1, answer: "3" },
+                Entry { year: 2025, day: 1, hash: "abc", part: 2, answer: "6" },
+                Entry { year: 2025, day: 11, hash: "ghi", part: 1, answer: "4" },
+            ],
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Leveled events that solvers emit to explain how they reach an answer,
+//! shown on stderr with `-v` ([`debug!`](crate::debug)) or `-vv`
+//! ([`trace!`](crate::trace)). Without the `trace` feature the macros expand
+//! to dead code: their arguments are type-checked but never evaluated.
+
+use std::{
+    fmt,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Level {
+    /// A few events per part, e.g., the size of a result.
+    Debug = 1,
+    /// Events per step, e.g., every candidate that is rejected.
+    Trace = 2,
+}
+
+/// The most verbose level shown, or 0 for none.
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Shows events up to `level` from now on, or none.
+pub fn set_level(level: Option<Level>) {
+    LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    cfg!(feature = "trace") && LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+#[doc(hidden)]
+pub fn emit(level: Level, module: &str, args: fmt::Arguments<'_>) {
+    let label = match level {
+        Level::Debug => "debug",
+        Level::Trace => "trace",
+    };
+
+    eprintln!("[{}] {}: {}", label, module.strip_prefix("aoc25::").unwrap_or(module), args);
+}
+
+/// Emits an event at [`Level::Debug`], formatted like `format!`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::__event!($crate::trace::Level::Debug, $($arg)+)
+    };
+}
+
+/// Emits an event at [`Level::Trace`], formatted like `format!`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::__event!($crate::trace::Level::Trace, $($arg)+)
+    };
+}
+
+#[cfg(feature = "trace")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __event {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __event {
+    ($level:expr, $($arg:tt)+) => {
+        if false {
+            let _ = format_args!($($arg)+);
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::trace::{Level, enabled, set_level};
+
+    #[test]
+    fn test_level() {
+        set_level(Some(Level::Debug));
+
+        assert_eq!(enabled(Level::Debug), cfg!(feature = "trace"));
+        assert!(!enabled(Level::Trace));
+
+        crate::debug!("shown with the `trace` feature: {}", 1);
+        crate::trace!("never shown: {}", 2);
+
+        set_level(None);
+
+        assert!(!enabled(Level::Debug));
+    }
+}
@@ -11,6 +11,7 @@ pub struct Dag<V>
 impl<V> Dag<V>
     where V: PartialEq + Eq + Hash + Clone,
 {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             edges: HashMap::new()
@@ -23,6 +24,7 @@ impl<V> Dag<V>
             .or_insert(vec![dst.clone()]);
     }
 
+    #[allow(clippy::needless_lifetimes)]
     pub fn neighbors<'a>(&self, src: &'a V) -> impl Iterator<Item = V> {
         match self.edges.get(src) {
             Some(neighbors) => Either::Left(neighbors.iter().cloned()),
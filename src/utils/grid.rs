@@ -1,11 +1,66 @@
-#[derive(Clone, Copy, PartialEq, Eq)]
+use std::{collections::HashSet, fmt};
+
+use crate::error::AocError;
+
+/// `(row, col)`, with rows growing downwards.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Pos(pub i64, pub i64);
 
-const DIRS8: &[Pos; 8] = &[
-    Pos(-1, -1), Pos(-1, 0), Pos(-1, 1),
-    Pos(0, -1), Pos(0, 1),
-    Pos(1, -1), Pos(1, 0), Pos(1, 1),
-];
+/// One of the eight directions to a neighbouring cell, as seen on screen.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Clockwise, starting from `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up, Direction::UpRight, Direction::Right, Direction::DownRight,
+        Direction::Down, Direction::DownLeft, Direction::Left, Direction::UpLeft,
+    ];
+
+    /// The four directions that share an edge, clockwise from `Up`.
+    pub const CARDINAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn offset(self) -> Pos {
+        match self {
+            Direction::Up => Pos(-1, 0),
+            Direction::UpRight => Pos(-1, 1),
+            Direction::Right => Pos(0, 1),
+            Direction::DownRight => Pos(1, 1),
+            Direction::Down => Pos(1, 0),
+            Direction::DownLeft => Pos(1, -1),
+            Direction::Left => Pos(0, -1),
+            Direction::UpLeft => Pos(-1, -1),
+        }
+    }
+
+    /// Turns by a quarter clockwise, e.g., from `Up` to `Right`.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns by a quarter counterclockwise, e.g., from `Up` to `Left`.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Turns clockwise by `eighths` of a full turn.
+    fn rotate(self, eighths: usize) -> Self {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+}
 
 impl std::ops::Add for Pos {
     type Output = Self;
@@ -15,6 +70,57 @@ impl std::ops::Add for Pos {
     }
 }
 
+/// Why [`Grid::from_str`] failed. Rows and columns are 1-based.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GridError {
+    /// A character that the cell type's `TryFrom<char>` rejects.
+    UnknownCell { row: usize, col: usize, found: char },
+    /// A row whose length differs from that of the first row.
+    Ragged { row: usize, expected: usize, found: usize },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::UnknownCell { row, col, found } => {
+                write!(f, "row {}, column {}: unexpected character {:?}", row, col, found)
+            },
+            GridError::Ragged { row, expected, found } => {
+                write!(f, "row {}: expected {} cells like the first row, found {}", row, expected, found)
+            },
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// Keeps the row as the line, since a grid usually spans the whole input.
+impl From<GridError> for AocError {
+    fn from(e: GridError) -> Self {
+        match e {
+            GridError::UnknownCell { row, col, found } => {
+                AocError::new(format!("column {}: unexpected character {:?}", col, found)).with_line(row)
+            },
+            GridError::Ragged { row, expected, found } => {
+                AocError::new(format!("expected {} cells like the first row, found {}", expected, found))
+                    .with_line(row)
+            },
+        }
+    }
+}
+
+/// The inverse of a cell's `TryFrom<char>`, for rendering a [`Grid`].
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl ToChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Grid<T: PartialEq + Eq + TryFrom<char>> {
     pub nrows: usize,
     pub ncols: usize,
@@ -22,26 +128,49 @@ pub struct Grid<T: PartialEq + Eq + TryFrom<char>> {
 }
 
 impl<T: PartialEq + Eq + TryFrom<char>> Grid<T> {
-    pub fn from_str(s: &str) -> Result<Self, ()> {
+    /// Parses one row per line, which may end in `\n` or `\r\n`. Trailing
+    /// line endings are ignored.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, GridError> {
         let mut nrows = 0usize;
-        let mut ncols = 0usize;
+        let mut ncols = None;
         let mut cells: Vec<_> = vec![];
 
-        // TODO: flatten() is enough?
-        s.lines().for_each(|row| {
-            ncols = row.chars().count();
+        for (i, line) in s.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let mut len = 0;
+
+            for (j, c) in line.chars().enumerate() {
+                let cell = T::try_from(c)
+                    .map_err(|_| GridError::UnknownCell { row: i + 1, col: j + 1, found: c })?;
+
+                cells.push(cell);
+                len += 1;
+            }
+
+            match ncols {
+                None => ncols = Some(len),
+                Some(expected) if expected != len => {
+                    return Err(GridError::Ragged { row: i + 1, expected, found: len });
+                },
+                Some(_) => {},
+            }
+
             nrows += 1;
-            cells.extend(row.chars().map(|c| c.try_into()).flatten());
-        });
-
-        if cells.len() == nrows * ncols {
-            Ok(Self {
-                nrows,
-                ncols,
-                cells,
-            })
-        } else {
-            Err(())
+        }
+
+        Ok(Self {
+            nrows,
+            ncols: ncols.unwrap_or(0),
+            cells,
+        })
+    }
+
+    /// An `nrows` x `ncols` grid with `f(pos)` at each `pos`.
+    pub fn from_fn(nrows: usize, ncols: usize, f: impl FnMut(Pos) -> T) -> Self {
+        Self {
+            nrows,
+            ncols,
+            cells: PosIterator { curr: 0, nrows, ncols }.map(f).collect(),
         }
     }
 
@@ -61,8 +190,112 @@ impl<T: PartialEq + Eq + TryFrom<char>> Grid<T> {
         }
     }
 
+    /// The cell next to `pos` in direction `dir`, if it is on the grid.
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<(Pos, &T)> {
+        let next = pos + dir.offset();
+
+        self.get(next).map(|cell| (next, cell))
+    }
+
+    /// The cells that share an edge with `pos`, clockwise from the one above.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        Direction::CARDINAL.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The cells that share an edge or a corner with `pos`, clockwise from the
+    /// one above.
+    pub fn neighbors8_with_pos(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        Direction::ALL.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
     pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = &T> {
-        DIRS8.iter().map(move |&dir| self.get(pos + dir)).flatten()
+        self.neighbors8_with_pos(pos).map(|(_, cell)| cell)
+    }
+
+    pub fn pos_iter(&self) -> PosIterator {
+        PosIterator {
+            curr: 0,
+            nrows: self.nrows,
+            ncols: self.ncols,
+        }
+    }
+
+    /// Borrows the `nrows` x `ncols` rectangle whose top left cell is at
+    /// `origin`, if it is all on the grid.
+    pub fn view(&self, origin: Pos, nrows: usize, ncols: usize) -> Option<GridView<'_, T>> {
+        let Pos(row, col) = origin;
+
+        if row < 0 || col < 0 || row as usize + nrows > self.nrows || col as usize + ncols > self.ncols {
+            return None;
+        }
+
+        Some(GridView {
+            grid: self,
+            origin,
+            nrows,
+            ncols,
+        })
+    }
+}
+
+impl<T: PartialEq + Eq + TryFrom<char> + Clone> Grid<T> {
+    /// Mirrors along the diagonal from the top left, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.ncols, self.nrows, |Pos(row, col)| Pos(col, row))
+    }
+
+    /// Rotates by a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        let last_row = self.nrows as i64 - 1;
+
+        self.remap(self.ncols, self.nrows, |Pos(row, col)| Pos(last_row - col, row))
+    }
+
+    /// Rotates by a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        let last_col = self.ncols as i64 - 1;
+
+        self.remap(self.ncols, self.nrows, |Pos(row, col)| Pos(col, last_col - row))
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let last_col = self.ncols as i64 - 1;
+
+        self.remap(self.nrows, self.ncols, |Pos(row, col)| Pos(row, last_col - col))
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let last_row = self.nrows as i64 - 1;
+
+        self.remap(self.nrows, self.ncols, |Pos(row, col)| Pos(last_row - row, col))
+    }
+
+    /// An `nrows` x `ncols` grid with the cell at `source(pos)` at each `pos`.
+    fn remap(&self, nrows: usize, ncols: usize, source: impl Fn(Pos) -> Pos) -> Self {
+        Self::from_fn(nrows, ncols, |pos| self.get(source(pos)).unwrap().clone())
+    }
+}
+
+/// A rectangle borrowed from a [`Grid`], see [`Grid::view`]. Positions are
+/// relative to its top left cell.
+pub struct GridView<'a, T: PartialEq + Eq + TryFrom<char>> {
+    grid: &'a Grid<T>,
+    origin: Pos,
+    pub nrows: usize,
+    pub ncols: usize,
+}
+
+impl<'a, T: PartialEq + Eq + TryFrom<char>> GridView<'a, T> {
+    pub fn get(&self, pos: Pos) -> Option<&'a T> {
+        let Pos(row, col) = pos;
+
+        if row < 0 || row >= self.nrows as i64 || col < 0 || col >= self.ncols as i64 {
+            None
+        } else {
+            self.grid.get(self.origin + pos)
+        }
     }
 
     pub fn pos_iter(&self) -> PosIterator {
@@ -72,6 +305,59 @@ impl<T: PartialEq + Eq + TryFrom<char>> Grid<T> {
             ncols: self.ncols,
         }
     }
+
+    /// Copies the rectangle into a grid of its own.
+    pub fn to_grid(&self) -> Grid<T> where T: Clone {
+        self.grid.remap(self.nrows, self.ncols, |pos| self.origin + pos)
+    }
+}
+
+impl<T: PartialEq + Eq + TryFrom<char> + ToChar> Grid<T> {
+    /// Renders like `Display`, with `mark` in place of the cells at
+    /// `positions`, e.g., to see which cells a solver picked.
+    pub fn overlay(&self, positions: impl IntoIterator<Item = Pos>, mark: char) -> Overlay<'_, T> {
+        Overlay {
+            grid: self,
+            positions: positions.into_iter().collect(),
+            mark,
+        }
+    }
+
+    fn render(&self, f: &mut fmt::Formatter<'_>, char_at: impl Fn(Pos, &T) -> char) -> fmt::Result {
+        for (i, pos) in self.pos_iter().enumerate() {
+            if i > 0 && i % self.ncols == 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{}", char_at(pos, self.get(pos).unwrap()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// One line per row, without a trailing newline, so that `from_str` reads
+/// back the same grid.
+impl<T: PartialEq + Eq + TryFrom<char> + ToChar> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, |_, cell| cell.to_char())
+    }
+}
+
+/// A [`Grid`] with some cells marked, see [`Grid::overlay`].
+pub struct Overlay<'a, T: PartialEq + Eq + TryFrom<char>> {
+    grid: &'a Grid<T>,
+    positions: HashSet<Pos>,
+    mark: char,
+}
+
+impl<T: PartialEq + Eq + TryFrom<char> + ToChar> fmt::Display for Overlay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.render(f, |pos, cell| match self.positions.contains(&pos) {
+            true => self.mark,
+            false => cell.to_char(),
+        })
+    }
 }
 
 pub struct PosIterator {
@@ -88,7 +374,7 @@ impl Iterator for PosIterator {
             return None
         }
 
-        let curr_pos = Pos((self.curr / self.nrows) as i64, (self.curr % self.nrows) as i64);
+        let curr_pos = Pos((self.curr / self.ncols) as i64, (self.curr % self.ncols) as i64);
         self.curr += 1;
         Some(curr_pos)
     }
@@ -96,9 +382,10 @@ impl Iterator for PosIterator {
 
 #[cfg(test)]
 mod test {
-    use crate::utils::grid::{Grid, Pos};
+    use crate::error::AocError;
+    use crate::utils::grid::{Direction, Grid, GridError, Pos, ToChar};
 
-    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
     enum Cell {
         A,
         B,
@@ -118,6 +405,16 @@ mod test {
         }
     }
 
+    impl ToChar for Cell {
+        fn to_char(&self) -> char {
+            match self {
+                Cell::A => 'A',
+                Cell::B => 'B',
+                Cell::C => 'C',
+            }
+        }
+    }
+
     #[test]
     fn test_grid() {
         let grid = Grid::<Cell>::from_str("AAB
@@ -157,4 +454,135 @@ CAB");
 
         assert_eq!(grid.pos_iter().count(), 12);
     }
+
+    #[test]
+    fn test_pos_iter() {
+        // More rows than columns, so that rows are split by `ncols`.
+        let grid = Grid::<Cell>::from_str("AB\nCA\nBC").unwrap();
+
+        assert_eq!(
+            grid.pos_iter().collect::<Vec<_>>(),
+            vec![Pos(0, 0), Pos(0, 1), Pos(1, 0), Pos(1, 1), Pos(2, 0), Pos(2, 1)],
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::<Cell>::from_str("AB\r\nCA\r\n\r\n").unwrap();
+
+        assert_eq!((grid.nrows, grid.ncols), (2, 2));
+        assert_eq!(grid.to_string(), "AB\nCA");
+        assert_eq!(Grid::<Cell>::from_str("AB\nCA\n\n"), Ok(grid));
+        assert_eq!(Grid::<Cell>::from_str("").map(|grid| grid.nrows), Ok(0));
+
+        assert_eq!(
+            Grid::<Cell>::from_str("AB\nCx\nAB"),
+            Err(GridError::UnknownCell { row: 2, col: 2, found: 'x' }),
+        );
+        assert_eq!(
+            Grid::<Cell>::from_str("AB\nCAB\nA"),
+            Err(GridError::Ragged { row: 2, expected: 2, found: 3 }),
+        );
+        assert_eq!(
+            Grid::<Cell>::from_str("AB\n\nAB"),
+            Err(GridError::Ragged { row: 2, expected: 2, found: 0 }),
+        );
+
+        let err = AocError::from(Grid::<Cell>::from_str("AB\nCx").unwrap_err());
+        assert_eq!(err.to_string(), "line 2: column 2: unexpected character 'x'");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::<Cell>::from_str("AAB
+BCC
+CCA
+CAB").unwrap();
+
+        assert_eq!(
+            grid.neighbors4(Pos(0, 0)).collect::<Vec<_>>(),
+            vec![(Pos(0, 1), &Cell::A), (Pos(1, 0), &Cell::B)],
+        );
+        assert_eq!(grid.neighbors4(Pos(1, 1)).count(), 4);
+
+        assert_eq!(
+            grid.neighbors8_with_pos(Pos(3, 2)).collect::<Vec<_>>(),
+            vec![(Pos(2, 2), &Cell::A), (Pos(3, 1), &Cell::A), (Pos(2, 1), &Cell::C)],
+        );
+
+        assert_eq!(grid.step(Pos(1, 1), Direction::DownRight), Some((Pos(2, 2), &Cell::A)));
+        assert_eq!(grid.step(Pos(0, 1), Direction::Up), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let s = "AAB
+BCC
+CCA
+CAB";
+        let grid = Grid::<Cell>::from_str(s).unwrap();
+
+        assert_eq!(grid.to_string(), s);
+        assert_eq!(Grid::<Cell>::from_str(&grid.to_string()).unwrap(), grid);
+
+        assert_eq!(
+            grid.overlay([Pos(0, 0), Pos(2, 1), Pos(9, 9)], '#').to_string(),
+            "#AB
+BCC
+C#A
+CAB",
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::<char>::from_str("abc
+def").unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_horizontal().flip_vertical());
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = Grid::<Cell>::from_str("AAB
+BCC
+CCA
+CAB").unwrap();
+
+        let view = grid.view(Pos(1, 1), 3, 2).unwrap();
+
+        assert_eq!(view.get(Pos(0, 0)), Some(&Cell::C));
+        assert_eq!(view.get(Pos(2, 1)), Some(&Cell::B));
+        assert_eq!(view.get(Pos(0, 2)), None);
+        assert_eq!(view.pos_iter().count(), 6);
+        assert_eq!(view.to_grid().to_string(), "CC\nCA\nAB");
+
+        assert!(grid.view(Pos(0, 0), 4, 3).is_some());
+        assert!(grid.view(Pos(2, 2), 3, 1).is_none());
+        assert!(grid.view(Pos(-1, 0), 1, 1).is_none());
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+
+        for dir in Direction::ALL {
+            let Pos(row, col) = dir.offset();
+
+            assert_eq!(dir.opposite().offset(), Pos(-row, -col));
+            assert_eq!(dir.turn_right().offset(), Pos(col, -row));
+            assert_eq!(dir.turn_left().turn_right(), dir);
+        }
+    }
 }
@@ -85,12 +85,14 @@ pub struct IntervalTree<T>
 }
 
 impl<T: PartialEq + PartialOrd + Ord + Copy> IntervalTree<T> {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             root: None,
         }
     }
 
+    #[allow(clippy::result_unit_err)]
     pub fn insert(&mut self, start: T, end: T) -> Result<(), ()> {
         match &mut self.root {
             Some(node) => node.insert(start, end),
@@ -1,5 +1,7 @@
 pub mod grid;
 
+pub mod sparse_grid;
+
 pub mod interval_tree;
 
 pub mod union_find;
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use crate::utils::grid::{Direction, Grid, Pos};
+
+/// A grid that stores only the cells that were set, so that it can grow in
+/// any direction, including to negative positions.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// The cells of `grid` for which `keep` holds, at the same positions.
+    pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+        where T: PartialEq + Eq + TryFrom<char> + Clone,
+    {
+        grid.pos_iter()
+            .filter_map(|pos| grid.get(pos).filter(|cell| keep(cell)).map(|cell| (pos, cell.clone())))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Sets the cell at `pos`, returning the one it replaces.
+    pub fn insert(&mut self, pos: Pos, cell: T) -> Option<T> {
+        self.cells.insert(pos, cell)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// Every cell that is set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// The cell next to `pos` in direction `dir`, if it is set.
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<(Pos, &T)> {
+        let next = pos + dir.offset();
+
+        self.get(next).map(|cell| (next, cell))
+    }
+
+    /// The cells that are set and share an edge with `pos`, clockwise from
+    /// the one above.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        Direction::CARDINAL.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The cells that are set and share an edge or a corner with `pos`,
+    /// clockwise from the one above.
+    pub fn neighbors8_with_pos(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        Direction::ALL.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = &T> {
+        self.neighbors8_with_pos(pos).map(|(_, cell)| cell)
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle that
+    /// holds every cell, or `None` if there are none.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.cells.keys().fold(None, |bounds, &Pos(row, col)| match bounds {
+            None => Some((Pos(row, col), Pos(row, col))),
+            Some((Pos(top, left), Pos(bottom, right))) => Some((
+                Pos(top.min(row), left.min(col)),
+                Pos(bottom.max(row), right.max(col)),
+            )),
+        })
+    }
+
+    /// A dense copy of `bounds()`, with `fill` where no cell is set. The top
+    /// left corner of the bounds moves to `Pos(0, 0)`.
+    pub fn to_grid(&self, fill: T) -> Grid<T>
+        where T: PartialEq + Eq + TryFrom<char> + Clone,
+    {
+        let Some((top_left, Pos(bottom, right))) = self.bounds() else {
+            return Grid::from_fn(0, 0, |_| fill.clone());
+        };
+
+        let nrows = (bottom - top_left.0 + 1) as usize;
+        let ncols = (right - top_left.1 + 1) as usize;
+
+        Grid::from_fn(nrows, ncols, |pos| self.get(top_left + pos).unwrap_or(&fill).clone())
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::utils::grid::{Direction, Grid, Pos};
+    use crate::utils::sparse_grid::SparseGrid;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new();
+
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_grid('.').nrows, 0);
+
+        grid.insert(Pos(0, 0), '#');
+        grid.insert(Pos(-2, 3), '#');
+        grid.insert(Pos(1, -1), '@');
+
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.bounds(), Some((Pos(-2, -1), Pos(1, 3))));
+        assert_eq!(grid.get(Pos(1, -1)), Some(&'@'));
+        assert_eq!(grid.get(Pos(5, 5)), None);
+
+        *grid.get_mut(Pos(1, -1)).unwrap() = '#';
+
+        assert_eq!(grid.neighbors8_with_pos(Pos(0, 0)).collect::<Vec<_>>(), vec![(Pos(1, -1), &'#')]);
+        assert_eq!(grid.neighbors4(Pos(0, 0)).count(), 0);
+        assert_eq!(grid.step(Pos(0, -1), Direction::Right), Some((Pos(0, 0), &'#')));
+
+        assert_eq!(grid.to_grid('.').to_string(), "....#\n.....\n.#...\n#....");
+
+        assert_eq!(grid.remove(Pos(-2, 3)), Some('#'));
+        assert_eq!(grid.bounds(), Some((Pos(0, -1), Pos(1, 0))));
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let dense = Grid::<char>::from_str("..#\n#..").unwrap();
+        let sparse = SparseGrid::from_grid(&dense, |&c| c == '#');
+
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(Pos(0, 2)), Some(&'#'));
+        assert_eq!(sparse.get(Pos(0, 0)), None);
+        assert_eq!(sparse.to_grid('.'), dense);
+    }
+}
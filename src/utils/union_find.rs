@@ -68,6 +68,7 @@ mod test {
     use crate::utils::union_find::UnionFind;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_union_find_simple() {
         let mut a = UnionFind::new('a');
         let mut b = UnionFind::new('b');
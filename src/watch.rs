@@ -0,0 +1,81 @@
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// Polls the modification times of some files, which need not exist yet.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    modified: Option<Vec<Option<SystemTime>>>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        Self {
+            paths,
+            modified: None,
+        }
+    }
+
+    /// Whether any of the files was modified, created or removed since the
+    /// last call. Always true on the first call.
+    pub fn changed(&mut self) -> bool {
+        let modified = self.paths.iter()
+            .map(|path| fs::metadata(path).and_then(|metadata| metadata.modified()).ok())
+            .collect::<Vec<_>>();
+
+        if self.modified.as_ref() == Some(&modified) {
+            return false;
+        }
+
+        self.modified = Some(modified);
+        true
+    }
+
+    /// Blocks until [`changed`](Self::changed), checking every `interval`.
+    pub fn wait(&mut self, interval: Duration) {
+        while !self.changed() {
+            thread::sleep(interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs::{self, File},
+        time::{Duration, SystemTime},
+    };
+
+    use crate::watch::Watcher;
+
+    #[test]
+    fn test_watcher() {
+        let path = std::env::temp_dir().join(format!("aoc25-watch-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut watcher = Watcher::new(vec![path.clone()]);
+
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        fs::write(&path, "1").unwrap();
+        File::options().write(true).open(&path).unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(1)).unwrap();
+
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        File::options().write(true).open(&path).unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(2)).unwrap();
+
+        watcher.wait(Duration::from_millis(1));
+        assert!(!watcher.changed());
+
+        fs::remove_file(&path).unwrap();
+
+        assert!(watcher.changed());
+    }
+}
@@ -1,62 +1,101 @@
-const N: i32 = 100;
-
-fn parse_rotations() -> impl Iterator<Item = i32> {
-    std::io::stdin().lines()
-        .filter_map(|line| {
-            match line {
-                Err(_) => None,
-                Ok(line) => Some({
-                    let sign = match line.chars().nth(0).unwrap() {
-                        'L' => -1,
-                        'R' => 1,
-                        _ => return None,
-                    };
-                    sign * line[1..].parse::<i32>().unwrap()
-                }),
-            }
-        })
-}
+use crate::answer::Answer;
+use crate::error::{AocError, Result, parse_lines};
+use crate::params::{Param, ParamInfo, Params};
+use crate::solution::Solution;
 
-pub fn part1() -> i64 {
-    let mut curr = 50i32;
-    let mut password = 0i64;
+const SIZE: Param<i32> = Param::new("size", 100, "number of positions on the dial");
 
-    for delta in parse_rotations() {
-        curr = (curr + delta).rem_euclid(N);
+const START: Param<i32> = Param::new("start", 50, "position the dial points at initially");
 
-        // check if the dial points to zero as a result of current rotation.
-        if curr == 0 {
-            password += 1;
-        }
-    }
+pub struct Dial {
+    size: i32,
+    start: i32,
+    rotations: Vec<i32>,
+}
+
+fn parse_rotations(input: &str) -> Result<Vec<i32>> {
+    parse_lines(input, |line| {
+        let sign = match line.chars().next() {
+            Some('L') => -1,
+            Some('R') => 1,
+            _ => return Err(AocError::new(format!("expected `L` or `R`, found {:?}", line))),
+        };
 
-    password
+        line[1..].parse::<i32>()
+            .map(|amount| sign * amount)
+            .map_err(|e| AocError::new(format!("invalid amount {:?}: {}", &line[1..], e)))
+    })
 }
 
-pub fn part2() -> i64 {
-    let mut curr = 50i32;
-    let mut next;
-    let mut password = 0i64;
+pub struct Day01;
 
-    for delta in parse_rotations() {
-        // if `N * m <= amount < N * (m + 1)`, then the dial shall point to
-        // zero for `m` times.
-        password += (delta.abs() / N) as i64;
+impl Solution for Day01 {
+    type Input = Dial;
 
-        next = curr + delta % N;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        self.parse_with(input, &Params::default())
+    }
+
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![SIZE.info(), START.info()]
+    }
+
+    fn parse_with(&self, input: &str, params: &Params) -> Result<Self::Input> {
+        let (size, start) = (SIZE.get(params)?, START.get(params)?);
 
-        // check if remaining clicks cause an underflow or overflow
-        if curr * next < 0 || next > N {
-            password += 1;
+        if size <= 0 || !(0..size).contains(&start) {
+            return Err(AocError::new(format!("cannot start at {} on a dial of size {}", start, size)));
         }
 
-        curr = next.rem_euclid(N);
+        Ok(Dial {
+            size,
+            start,
+            rotations: parse_rotations(input)?,
+        })
+    }
+
+    fn part1(&self, dial: &Self::Input) -> Result<Answer> {
+        let mut curr = dial.start;
+        let mut password = 0i64;
 
-        // check if the dial points to zero as a result of current rotation.
-        if curr == 0 {
-            password += 1;
+        for &delta in &dial.rotations {
+            curr = (curr + delta).rem_euclid(dial.size);
+
+            // check if the dial points to zero as a result of current rotation.
+            if curr == 0 {
+                password += 1;
+            }
         }
+
+        Ok(password.into())
     }
 
-    password
+    fn part2(&self, dial: &Self::Input) -> Result<Answer> {
+        let n = dial.size;
+        let mut curr = dial.start;
+        let mut next;
+        let mut password = 0i64;
+
+        for &delta in &dial.rotations {
+            // if `N * m <= amount < N * (m + 1)`, then the dial shall point to
+            // zero for `m` times.
+            password += (delta.abs() / n) as i64;
+
+            next = curr + delta % n;
+
+            // check if remaining clicks cause an underflow or overflow
+            if curr * next < 0 || next > n {
+                password += 1;
+            }
+
+            curr = next.rem_euclid(n);
+
+            // check if the dial points to zero as a result of current rotation.
+            if curr == 0 {
+                password += 1;
+            }
+        }
+
+        Ok(password.into())
+    }
 }
@@ -1,22 +1,27 @@
-fn parse_ranges() -> impl Iterator<Item = (u64, u64)> {
-    std::io::stdin().lines()
-        .flatten()
-        .flat_map(|line| {
-            line.split(',')
-                .filter_map(|range| {
-                    let parts: Vec<_> = range.split('-')
-                        .map(|part| part.parse::<u64>())
-                        .flatten()
-                        .collect();
-
-                    if parts.len() > 0 {
-                        Some((parts[0], parts[1]))
-                    } else {
-                        None
-                    }
-                })
-            .collect::<Vec<_>>()
-        })
+use crate::answer::Answer;
+use crate::error::{AocError, Result, parse_lines};
+use crate::solution::Solution;
+
+fn parse_range(range: &str) -> Result<(u64, u64)> {
+    let (lo, hi) = range.split_once('-')
+        .ok_or_else(|| AocError::new(format!("expected `lo-hi`, found {:?}", range)))?;
+
+    match (lo.parse::<u64>(), hi.parse::<u64>()) {
+        (Ok(lo), Ok(hi)) if lo <= hi => Ok((lo, hi)),
+        _ => Err(AocError::new(format!("invalid range {:?}", range))),
+    }
+}
+
+fn parse_ranges(input: &str) -> Result<Vec<(u64, u64)>> {
+    Ok(parse_lines(input, |line| {
+        line.split(',')
+            .filter(|range| !range.is_empty())
+            .map(parse_range)
+            .collect::<Result<Vec<_>>>()
+    })?
+    .into_iter()
+    .flatten()
+    .collect())
 }
 
 fn is_invalid(x: u64) -> bool {
@@ -28,7 +33,7 @@ fn is_invalid(x: u64) -> bool {
 fn multiplier(len_pattern: u32, num_repeats: u32) -> u64 {
     (0..num_repeats - 1)
         .fold(1, |acc, _| {
-            acc * 10u64.pow(len_pattern as u32) + 1
+            acc * 10u64.pow(len_pattern) + 1
         })
 }
 
@@ -36,7 +41,7 @@ fn multiplier(len_pattern: u32, num_repeats: u32) -> u64 {
 fn sum_invalid_ids_in_range(lo: u64, hi: u64, part1: bool) -> u64 {
     let mut res = 0u64;
 
-    let num_max_digits = hi.ilog10() + 1;
+    let num_max_digits = hi.checked_ilog10().unwrap_or(0) + 1;
 
     for len_pattern in 1..=(num_max_digits / 2) {
         for num_repeats in 2..=(num_max_digits / len_pattern) {
@@ -69,20 +74,30 @@ fn sum_invalid_ids_in_range(lo: u64, hi: u64, part1: bool) -> u64 {
     res
 }
 
-pub fn part1() -> i64 {
-    parse_ranges()
-        .map(|(lo, hi)| {
-            sum_invalid_ids_in_range(lo, hi, true)
-        })
-        .sum::<u64>() as i64
-}
+pub struct Day02;
 
-pub fn part2() -> i64 {
-    parse_ranges()
-        .map(|(lo, hi)| {
-            sum_invalid_ids_in_range(lo, hi, false)
-        })
-        .sum::<u64>() as i64
+impl Solution for Day02 {
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_ranges(input)
+    }
+
+    fn part1(&self, ranges: &Self::Input) -> Result<Answer> {
+        Ok(ranges.iter()
+            .map(|&(lo, hi)| {
+                sum_invalid_ids_in_range(lo, hi, true)
+            })
+            .sum::<u64>().into())
+    }
+
+    fn part2(&self, ranges: &Self::Input) -> Result<Answer> {
+        Ok(ranges.iter()
+            .map(|&(lo, hi)| {
+                sum_invalid_ids_in_range(lo, hi, false)
+            })
+            .sum::<u64>().into())
+    }
 }
 
 
@@ -98,6 +113,7 @@ mod test {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn test_is_invalid() {
         assert_eq!(is_invalid(123123), true);
         assert_eq!(is_invalid(1231), false);
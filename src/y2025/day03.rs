@@ -1,6 +1,6 @@
-fn parse_banks() -> impl Iterator<Item = String> {
-    std::io::stdin().lines().flatten()
-}
+use crate::answer::Answer;
+use crate::error::{AocError, Result, parse_lines};
+use crate::solution::Solution;
 
 fn find_max_joltage(bank: &str, num_batteries: usize) -> i64 {
     (1..=num_batteries)
@@ -15,16 +15,43 @@ fn find_max_joltage(bank: &str, num_batteries: usize) -> i64 {
         .0
 }
 
-pub fn part1() -> i64 {
-    parse_banks()
-        .map(|bank| find_max_joltage(&bank, 2))
-        .sum()
+fn total_joltage(banks: &[String], num_batteries: usize) -> Result<Answer> {
+    banks.iter()
+        .enumerate()
+        .map(|(i, bank)| {
+            if bank.len() < num_batteries {
+                Err(AocError::new(format!("bank has fewer than {} batteries", num_batteries))
+                    .with_line(i + 1))
+            } else {
+                Ok(find_max_joltage(bank, num_batteries))
+            }
+        })
+        .sum::<Result<i64>>()
+        .map(Answer::from)
 }
 
-pub fn part2() -> i64 {
-    parse_banks()
-        .map(|bank| find_max_joltage(&bank, 12))
-        .sum()
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_lines(input, |line| {
+            if line.chars().all(|c| c.is_ascii_digit()) {
+                Ok(String::from(line))
+            } else {
+                Err(AocError::new(format!("expected only digits, found {:?}", line)))
+            }
+        })
+    }
+
+    fn part1(&self, banks: &Self::Input) -> Result<Answer> {
+        total_joltage(banks, 2)
+    }
+
+    fn part2(&self, banks: &Self::Input) -> Result<Answer> {
+        total_joltage(banks, 12)
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,10 @@
-use std::io::Read;
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::Solution;
+use crate::utils::grid::{Grid, Pos, ToChar};
 
-use crate::utils::grid::{Grid, Pos};
-
-#[derive(PartialEq, Eq)]
-enum Cell {
+#[derive(Clone, PartialEq, Eq)]
+pub enum Cell {
     PaperRoll,
     Empty,
 }
@@ -20,18 +21,17 @@ impl TryFrom<char> for Cell {
     }
 }
 
-type Day4Grid = Grid<Cell>;
-
-fn parse_grid() -> Option<Day4Grid> {
-    let mut s = String::new();
-
-    if std::io::stdin().read_to_string(&mut s).is_err() {
-        return None;
+impl ToChar for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::PaperRoll => '@',
+            Cell::Empty => '.',
+        }
     }
-
-    Day4Grid::from_str(&s).ok()
 }
 
+pub type Day4Grid = Grid<Cell>;
+
 fn removable_cells(grid: &Day4Grid) -> impl Iterator<Item = Pos> {
     grid.pos_iter()
         .filter(|&pos| {
@@ -46,27 +46,37 @@ fn removable_cells(grid: &Day4Grid) -> impl Iterator<Item = Pos> {
         })
 }
 
-pub fn part1() -> i64 {
-    let grid = parse_grid().unwrap();
+pub struct Day04;
 
-    removable_cells(&grid).count() as i64
-}
+impl Solution for Day04 {
+    type Input = Day4Grid;
 
-pub fn part2() -> i64 {
-    let mut grid = parse_grid().unwrap();
-    let mut res = 0i64;
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Day4Grid::from_str(input)?)
+    }
 
-    loop {
-        let positions = removable_cells(&grid).collect::<Vec<_>>();
+    fn part1(&self, grid: &Self::Input) -> Result<Answer> {
+        crate::trace!("removable rolls:\n{}", grid.overlay(removable_cells(grid), 'x'));
 
-        res += positions.len() as i64;
+        Ok(removable_cells(grid).count().into())
+    }
 
-        if positions.len() == 0 {
-            break res;
-        }
+    fn part2(&self, grid: &Self::Input) -> Result<Answer> {
+        let mut grid = grid.clone();
+        let mut res = 0usize;
+
+        loop {
+            let positions = removable_cells(&grid).collect::<Vec<_>>();
+
+            res += positions.len();
 
-        for pos in positions {
-            *grid.get_mut(pos).unwrap() = Cell::Empty;
+            if positions.is_empty() {
+                break Ok(res.into());
+            }
+
+            for pos in positions {
+                *grid.get_mut(pos).unwrap() = Cell::Empty;
+            }
         }
     }
 }
\ No newline at end of file
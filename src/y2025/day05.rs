@@ -1,58 +1,117 @@
+use crate::answer::Answer;
+use crate::error::{AocError, Result};
+use crate::solution::{Alternative, Solution};
 use crate::utils::interval_tree::IntervalTree;
 
-pub fn part1() -> i64 {
-    let mut itree = IntervalTree::<u64>::new();
-    let mut ans = 0i64;
+pub struct Inventory {
+    ranges: Vec<(u64, u64)>,
+    ids: Vec<u64>,
+}
 
-    std::io::stdin().lines().flatten().for_each(|line| {
-        if line.contains('-') {
-            let (lower, upper) = {
-                let parts = line.split('-')
-                    .filter_map(|part| part.parse::<u64>().ok())
-                    .collect::<Vec<_>>();
-                (parts[0], parts[1] + 1) // convert ranges to [lower, upper)
-            };
+/// Sorts `ranges` and merges overlapping ones, so that they are disjoint.
+fn merge(ranges: &[(u64, u64)]) -> Vec<(u64, u64)> {
+    let mut ranges = ranges.to_vec();
 
-         _ = itree.insert(lower, upper);
-        }
-        else {
-            if let Ok(id) = line.parse::<u64>() {
-                if itree.query(id) > 0 {
-                    ans += 1;
-                }
-            }
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u64, u64)> = Vec::new();
+
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = std::cmp::max(*last_end, end),
+            _ => merged.push((start, end)),
         }
-    });
+    }
 
-    ans
+    merged
 }
 
-pub fn part2() -> i64 {
-    let mut ranges = std::io::stdin().lines().flatten()
-        .filter_map(|line| {
-            if line.contains('-') {
-                let (lower, upper) = {
-                    let parts = line.split('-')
-                        .filter_map(|part| part.parse::<u64>().ok())
-                        .collect::<Vec<_>>();
-                    (parts[0], parts[1] + 1) // convert ranges to [lower, upper)
-                };
-
-                Some((lower, upper))
-            } else {
-                None
-            }
+/// Part 1, checking every ID against every range.
+fn count_fresh_linear(inventory: &Inventory) -> Result<Answer> {
+    Ok(inventory.ids.iter()
+        .filter(|&&id| inventory.ranges.iter().any(|&(lower, upper)| lower <= id && id < upper))
+        .count().into())
+}
+
+/// Part 1, binary searching the merged ranges.
+fn count_fresh_merged(inventory: &Inventory) -> Result<Answer> {
+    let merged = merge(&inventory.ranges);
+
+    Ok(inventory.ids.iter()
+        .filter(|&&id| {
+            let i = merged.partition_point(|&(start, _)| start <= id);
+            i > 0 && id < merged[i - 1].1
         })
-        .collect::<Vec<_>>();
+        .count().into())
+}
+
+/// Part 2, summing the lengths of the merged ranges.
+fn count_all_fresh_merged(inventory: &Inventory) -> Result<Answer> {
+    Ok(merge(&inventory.ranges).into_iter()
+        .map(|(start, end)| end - start)
+        .sum::<u64>().into())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Inventory;
 
-    ranges.sort_unstable_by_key(|&(start, _)| start);
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let mut ranges = vec![];
+        let mut ids = vec![];
 
-    // NOTE: ranges are left-inclusive, i.e., [start, end)
-    ranges.into_iter().fold((0u64, 0u64), |(mut acc, max_end), (start, end)| {
-        if end > max_end {
-            acc += end - std::cmp::max(start, max_end);
+        for (i, line) in input.lines().enumerate() {
+            if let Some((lower, upper)) = line.split_once('-') {
+                match (lower.parse::<u64>(), upper.parse::<u64>()) {
+                    // convert ranges to [lower, upper)
+                    (Ok(lower), Ok(upper)) => ranges.push((lower, upper + 1)),
+                    _ => return Err(AocError::new(format!("invalid range {:?}", line)).with_line(i + 1)),
+                }
+            }
+            else if !line.is_empty() {
+                match line.parse::<u64>() {
+                    Ok(id) => ids.push(id),
+                    Err(_) => return Err(AocError::new(format!("invalid ID {:?}", line)).with_line(i + 1)),
+                }
+            }
+        }
+
+        Ok(Inventory { ranges, ids })
+    }
+
+    fn part1(&self, inventory: &Self::Input) -> Result<Answer> {
+        let mut itree = IntervalTree::<u64>::new();
+
+        for &(lower, upper) in inventory.ranges.iter() {
+            _ = itree.insert(lower, upper);
         }
 
-        (acc, std::cmp::max(max_end, end))
-    }).0 as i64
-}
\ No newline at end of file
+        Ok(inventory.ids.iter()
+            .filter(|&&id| itree.query(id) > 0)
+            .count().into())
+    }
+
+    fn part2(&self, inventory: &Self::Input) -> Result<Answer> {
+        let mut ranges = inventory.ranges.clone();
+
+        ranges.sort_unstable_by_key(|&(start, _)| start);
+
+        // NOTE: ranges are left-inclusive, i.e., [start, end)
+        Ok(ranges.into_iter().fold((0u64, 0u64), |(mut acc, max_end), (start, end)| {
+            if end > max_end {
+                acc += end - std::cmp::max(start, max_end);
+            }
+
+            (acc, std::cmp::max(max_end, end))
+        }).0.into())
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self::Input>> {
+        vec![
+            Alternative { name: "linear", part: 1, solve: count_fresh_linear },
+            Alternative { name: "merged", part: 1, solve: count_fresh_merged },
+            Alternative { name: "merged", part: 2, solve: count_all_fresh_merged },
+        ]
+    }
+}
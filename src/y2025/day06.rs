@@ -1,3 +1,8 @@
+use crate::answer::Answer;
+use crate::error::{AocError, Result, parse_lines};
+use crate::solution::Solution;
+use crate::utils::grid::{Grid, Pos};
+
 #[derive(Debug)]
 enum Op {
     Add,
@@ -53,94 +58,126 @@ impl ProblemBuilder {
         self.op.replace(op);
     }
 
-    fn build(self) -> Problem {
-        Problem {
-            op: self.op.unwrap(),
+    fn build(self) -> Option<Problem> {
+        Some(Problem {
+            op: self.op?,
             operands: self.operands,
-        }
+        })
     }
 }
 
-pub fn part1() -> i64 {
-    let mut builders = vec![];
+pub struct Day06;
 
-    std::io::stdin().lines().flatten()
-        .for_each(|line| {
-            line.split_whitespace().enumerate().for_each(|(i, part)| {
+impl Solution for Day06 {
+    type Input = Vec<String>;
 
-                if builders.len() < i + 1 {
-                    builders.push(ProblemBuilder::new());
-                }
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let rows = parse_lines(input, |line| {
+            match line.chars().find(|&c| !(c.is_ascii_digit() || c == ' ' || c == '+' || c == '*')) {
+                Some(c) => Err(AocError::new(format!("unexpected character {:?}", c))),
+                None => Ok(String::from(line)),
+            }
+        })?;
+
+        if rows.is_empty() {
+            return Err(AocError::new("empty worksheet"));
+        }
+
+        Ok(rows)
+    }
+
+    fn part1(&self, rows: &Self::Input) -> Result<Answer> {
+        let mut builders = vec![];
+
+        rows.iter()
+            .for_each(|line| {
+                line.split_whitespace().enumerate().for_each(|(i, part)| {
 
-                if let Some(builder) = builders.get_mut(i) {
-                    if let Ok(operand) = part.parse::<u64>() {
-                        builder.push_operand(operand);
-                    } else if let Ok(op) = Op::try_from(part.chars().take(1).collect::<Vec<_>>()[0]) {
-                        builder.set_op(op);
+                    if builders.len() < i + 1 {
+                        builders.push(ProblemBuilder::new());
                     }
-                }
+
+                    if let Some(builder) = builders.get_mut(i) {
+                        if let Ok(operand) = part.parse::<u64>() {
+                            builder.push_operand(operand);
+                        } else if let Ok(op) = Op::try_from(part.chars().take(1).collect::<Vec<_>>()[0]) {
+                            builder.set_op(op);
+                        }
+                    }
+                })
+            });
+
+        builders.into_iter()
+            .enumerate()
+            .map(|(i, builder)| {
+                builder.build()
+                    .map(|problem| problem.eval())
+                    .ok_or_else(|| AocError::new(format!("problem {} has no operator", i + 1)))
             })
-        });
+            .sum::<Result<u64>>()
+            .map(Answer::from)
+    }
 
-    builders.into_iter()
-        .map(|builder| {
-            builder.build().eval()
-        })
-        .sum::<u64>() as i64
-}
+    fn part2(&self, rows: &Self::Input) -> Result<Answer> {
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
 
-fn get_operand(s: &str) -> Option<u64> {
-    let operands = s.split_whitespace()
-        .filter_map(|part| part.parse::<u64>().ok())
-        .collect::<Vec<_>>();
+        // Each row of the transposed worksheet is one of its columns, i.e.,
+        // the digits of an operand from top to bottom and maybe an operator.
+        let sheet = Grid::<char>::from_str(
+            &rows.iter().map(|row| format!("{:<width$}", row)).collect::<Vec<_>>().join("\n"),
+        )?.transpose();
 
-    if operands.len() > 0 {
-        Some(operands[0])
-    } else {
-        None
-    }
-}
+        let mut builders = vec![ProblemBuilder::new()];
 
-fn step(rows: &mut Vec<String>) -> Result<(Option<u64>, Option<Op>), ()> {
-    let mut s = rows.iter_mut().filter_map(|row| row.pop()).collect::<String>();
+        for i in 0..sheet.nrows as i64 {
+            let column = (0..sheet.ncols as i64)
+                .filter_map(|j| sheet.get(Pos(i, j)))
+                .collect::<String>();
 
-    if s.len() < rows.len() {
-        return Err(());
-    }
+            // A blank column separates two problems.
+            if column.trim().is_empty() {
+                if !builders.last().unwrap().operands.is_empty() {
+                    builders.push(ProblemBuilder::new());
+                }
 
-    let mut op = s.split_off(s.len() - 1);
+                continue;
+            }
 
-    Ok((
-        get_operand(&s),
-        Op::try_from(op.pop().unwrap()).ok(),
-    ))
-}
+            let builder = builders.last_mut().unwrap();
+            let (digits, op) = column.split_at(column.len() - 1);
 
-pub fn part2() -> i64 {
-    let mut rows = std::io::stdin().lines().flatten().collect::<Vec<_>>();
-
-    let mut operands: Vec<u64> = vec![];
-    let mut res = 0i64;
-
-    while let Ok((operand, op)) = step(&mut rows) {
-        match (operand, op) {
-            (Some(operand), None) => {
-                operands.push(operand);
-            },
-            (Some(operand), Some(op)) => {
-                operands.push(operand);
-                res += match op {
-                    Op::Add => operands.iter().sum::<u64>(),
-                    Op::Mul => operands.iter().product::<u64>(),
-                } as i64;
-            },
-            (None, _) => {
-                operands.clear();
+            if let Some(operand) = get_operand(digits) {
+                builder.push_operand(operand);
+            }
+
+            if let Ok(op) = Op::try_from(op.chars().next().unwrap()) {
+                builder.set_op(op);
             }
         }
+
+        builders.into_iter()
+            .filter(|builder| !builder.operands.is_empty())
+            .enumerate()
+            .map(|(i, builder)| {
+                builder.build()
+                    .map(|problem| problem.eval())
+                    .ok_or_else(|| AocError::new(format!("problem {} has no operator", i + 1)))
+            })
+            .sum::<Result<u64>>()
+            .map(Answer::from)
     }
+}
 
-    res
+fn get_operand(s: &str) -> Option<u64> {
+    let operands = s.split_whitespace()
+        .filter_map(|part| part.parse::<u64>().ok())
+        .collect::<Vec<_>>();
+
+    if !operands.is_empty() {
+        Some(operands[0])
+    } else {
+        None
+    }
 }
 
 #[cfg(test)]
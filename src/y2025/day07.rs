@@ -1,9 +1,10 @@
-use std::io::Read;
-
-use crate::utils::grid::{Grid, Pos};
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::Solution;
+use crate::utils::grid::{Grid, Pos, ToChar};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum Cell {
+pub enum Cell {
     Source,
     Empty,
     Ray,
@@ -17,60 +18,27 @@ impl TryFrom<char> for Cell {
         match value {
             'S' => Ok(Cell::Source),
             '.' => Ok(Cell::Empty),
+            '|' => Ok(Cell::Ray),
             '^' => Ok(Cell::Splitter),
             _ => Err(())
         }
     }
 }
 
-pub fn part1() -> i64 {
-    let mut rays: Vec<Cell> = vec![];
-    let mut num_splits = 0i64;
-
-    std::io::stdin().lines().flatten()
-        .for_each(|line| {
-            if rays.len() < line.len() {
-                line.char_indices().for_each(|_| {
-                    rays.push(Cell::Empty);
-                });
-            }
-
-            line.chars().enumerate().for_each(|(i, char)| {
-                match Cell::try_from(char) {
-                    Ok(Cell::Source) => {
-                        rays[i] = Cell::Ray;
-                    },
-                    Ok(Cell::Splitter) => {
-                        match rays[i] {
-                            Cell::Ray => {
-                                num_splits += 1;
-
-                                if let Some(cell) = rays.get_mut(i - 1) {
-                                    *cell = Cell::Ray;
-                                }
-
-                                if let Some(cell) = rays.get_mut(i + 1) {
-                                    *cell = Cell::Ray;
-                                }
-
-                                if let Some(cell)  = rays.get_mut(i) {
-                                    *cell = Cell::Empty;
-                                }
-                            },
-                            _ => {},
-                        }
-                    },
-                    Ok(Cell::Empty) => {},
-                    _ => {}
-                }
-            })
-        });
-
-    num_splits
+impl ToChar for Cell {
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Source => 'S',
+            Cell::Empty => '.',
+            Cell::Ray => '|',
+            Cell::Splitter => '^',
+        }
+    }
 }
 
+/// A cell and the number of timelines in which a beam passes through it.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct AugmentedCell(Cell, usize);
+pub struct AugmentedCell(Cell, usize);
 
 impl TryFrom<char> for AugmentedCell {
     type Error = ();
@@ -79,51 +47,126 @@ impl TryFrom<char> for AugmentedCell {
         match value {
             'S' => Ok(AugmentedCell(Cell::Source, 0)),
             '.' => Ok(AugmentedCell(Cell::Empty, 0)),
+            // A beam, as rendered by `to_char`. Its timelines are for the
+            // solver to count, not the input.
+            '|' => Ok(AugmentedCell(Cell::Empty, 0)),
             '^' => Ok(AugmentedCell(Cell::Splitter, 0)),
             _ => Err(())
         }
     }
 }
 
-type Laboratory = Grid<AugmentedCell>;
-
-pub fn part2() -> i64 {
-    let mut s =  String::new();
-
-    std::io::stdin().read_to_string(&mut s).unwrap();
-
-    let mut lab = Laboratory::from_str(&s).unwrap();
-
-    for i in 0..lab.nrows as i64 {
-        (0..lab.ncols as i64).for_each(|j| {
-            let cell = lab.get_mut(Pos(i, j)).unwrap().clone();
-
-            if cell.0 == Cell::Source {
-                lab.get_mut(Pos(i, j)).unwrap().1 = 1;
-                lab.get_mut(Pos(i + 1, j)).unwrap().1 = 1;
-            }
-
-            match cell.0 {
-                Cell::Empty=> {
-                    if let Some(next_cell) = lab.get_mut(Pos(i + 1, j)) {
-                        next_cell.1 += cell.1;
-                    }
-                },
-                Cell::Splitter => {
-                    if let Some(next_cell) = lab.get_mut(Pos(i + 1, j - 1)) {
-                        next_cell.1 += cell.1;
-                    }
-
-                    if let Some(next_cell) = lab.get_mut(Pos(i + 1, j + 1)) {
-                        next_cell.1 += cell.1;
-                    }
-                },
-                _ => {},
-            }
-        });
+impl ToChar for AugmentedCell {
+    fn to_char(&self) -> char {
+        match self {
+            AugmentedCell(Cell::Empty, n) if *n > 0 => Cell::Ray.to_char(),
+            AugmentedCell(cell, _) => cell.to_char(),
+        }
+    }
+}
+
+pub type Laboratory = Grid<AugmentedCell>;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Laboratory;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(Laboratory::from_str(input)?)
+    }
+
+    fn part1(&self, lab: &Self::Input) -> Result<Answer> {
+        let mut rays: Vec<Cell> = vec![Cell::Empty; lab.ncols];
+        let mut num_splits = 0usize;
+
+        for i in 0..lab.nrows as i64 {
+            (0..lab.ncols).for_each(|j| {
+                match lab.get(Pos(i, j as i64)).map(|cell| cell.0) {
+                    Some(Cell::Source) => {
+                        rays[j] = Cell::Ray;
+                    },
+                    Some(Cell::Splitter) if rays[j] == Cell::Ray => {
+                        num_splits += 1;
+
+                        if let Some(cell) = rays.get_mut(j - 1) {
+                            *cell = Cell::Ray;
+                        }
+
+                        if let Some(cell) = rays.get_mut(j + 1) {
+                            *cell = Cell::Ray;
+                        }
+
+                        if let Some(cell)  = rays.get_mut(j) {
+                            *cell = Cell::Empty;
+                        }
+                    },
+                    _ => {}
+                }
+            });
+        }
+
+        Ok(num_splits.into())
     }
 
-    (0..lab.ncols as i64)
-        .filter_map(|j| lab.get(Pos(lab.nrows as i64 - 1, j)).map(|cell| cell.1))
-        .sum::<usize>() as i64
-}
\ No newline at end of file
+    fn part2(&self, lab: &Self::Input) -> Result<Answer> {
+        let mut lab = lab.clone();
+
+        for i in 0..lab.nrows as i64 {
+            (0..lab.ncols as i64).for_each(|j| {
+                let cell = *lab.get_mut(Pos(i, j)).unwrap();
+
+                if cell.0 == Cell::Source {
+                    lab.get_mut(Pos(i, j)).unwrap().1 = 1;
+                    lab.get_mut(Pos(i + 1, j)).unwrap().1 = 1;
+                }
+
+                match cell.0 {
+                    Cell::Empty=> {
+                        if let Some(next_cell) = lab.get_mut(Pos(i + 1, j)) {
+                            next_cell.1 += cell.1;
+                        }
+                    },
+                    Cell::Splitter => {
+                        if let Some(next_cell) = lab.get_mut(Pos(i + 1, j - 1)) {
+                            next_cell.1 += cell.1;
+                        }
+
+                        if let Some(next_cell) = lab.get_mut(Pos(i + 1, j + 1)) {
+                            next_cell.1 += cell.1;
+                        }
+                    },
+                    _ => {},
+                }
+            });
+        }
+
+        crate::trace!("beams:\n{}", lab);
+
+        Ok((0..lab.ncols as i64)
+            .filter_map(|j| lab.get(Pos(lab.nrows as i64 - 1, j)).map(|cell| cell.1))
+            .sum::<usize>().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solution::Solution;
+    use crate::utils::grid::Grid;
+    use crate::y2025::day07::{Cell, Day07, Laboratory};
+
+    #[test]
+    fn test_render_round_trip() {
+        let lab = "..S..\n..|..\n.|^|.\n.|.|.";
+        let grid = Grid::<Cell>::from_str(lab).unwrap();
+
+        assert_eq!(grid.to_string(), lab);
+        assert_eq!(Grid::<Cell>::from_str(&grid.to_string()).unwrap(), grid);
+
+        // Beams are drawn, but are empty cells to the solver.
+        let unlit = Laboratory::from_str("..S..\n.....\n..^..\n.....").unwrap();
+
+        assert_eq!(Laboratory::from_str(lab).unwrap(), unlit);
+        assert_eq!(Day07.part2(&Laboratory::from_str(lab).unwrap()), Day07.part2(&unlit));
+    }
+}
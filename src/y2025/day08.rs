@@ -3,10 +3,14 @@ use std::{cmp::Reverse, collections::BinaryHeap, rc::Rc};
 
 use itertools::Itertools;
 
+use crate::answer::Answer;
+use crate::error::{AocError, Result, parse_lines};
+use crate::params::{Param, ParamInfo, Params};
+use crate::solution::Solution;
 use crate::utils::union_find::UnionFind;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
-struct Coord {
+pub struct Coord {
     x: i64,
     y: i64,
     z: i64,
@@ -14,22 +18,22 @@ struct Coord {
 
 impl Coord {
     fn dist_sq(&self, other: &Self) -> usize {
-        let dx = (self.x - other.x).abs() as usize;
-        let dy = (self.y - other.y).abs() as usize;
-        let dz = (self.z - other.z).abs() as usize;
+        let dx = self.x.abs_diff(other.x) as usize;
+        let dy = self.y.abs_diff(other.y) as usize;
+        let dz = self.z.abs_diff(other.z) as usize;
 
         dx * dx + dy * dy + dz * dz
     }
 }
 
 impl TryFrom<&str> for Coord {
-    type Error = ();
+    type Error = AocError;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         let parts = s.split(',').collect::<Vec<_>>();
 
         if parts.len() != 3 {
-            return Err(());
+            return Err(AocError::new(format!("expected `x,y,z`, found {:?}", s)));
         }
 
         let x = parts[0].parse::<i64>();
@@ -38,7 +42,7 @@ impl TryFrom<&str> for Coord {
 
         match (x, y, z) {
             (Ok(x), Ok(y), Ok(z)) => Ok(Coord { x, y, z }),
-            _ => Err(())
+            _ => Err(AocError::new(format!("invalid coordinates {:?}", s))),
         }
     }
 }
@@ -49,6 +53,7 @@ impl fmt::Display for Coord {
     }
 }
 
+#[allow(clippy::derive_ord_xor_partial_ord)]
 #[derive(Eq, Ord)]
 struct Edge {
     src: Coord,
@@ -59,8 +64,8 @@ struct Edge {
 impl Edge {
     fn new(src: &Coord, dst: &Coord) -> Self {
         Self {
-            src: src.clone(),
-            dst: dst.clone(),
+            src: *src,
+            dst: *dst,
             dist_sq: src.dist_sq(dst),
         }
     }
@@ -72,25 +77,20 @@ impl PartialEq for Edge {
     }
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Edge {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.dist_sq.partial_cmp(&other.dist_sq)
     }
 }
 
-fn parse_coordinates() -> Vec<UnionFind<Coord>> {
-    std::io::stdin().lines().flatten()
-        .filter_map(|line| {
-            if let Ok(coord) = Coord::try_from(line.as_str()) {
-                Some(UnionFind::new(coord))
-            } else {
-                None
-            }
-        })
+fn make_sets(coords: &[Coord]) -> Vec<UnionFind<Coord>> {
+    coords.iter()
+        .map(|&coord| UnionFind::new(coord))
         .collect::<Vec<_>>()
 }
 
-fn get_edges(coords: &Vec<UnionFind<Coord>>) -> BinaryHeap<Reverse<Edge>> {
+fn get_edges(coords: &[UnionFind<Coord>]) -> BinaryHeap<Reverse<Edge>> {
     let mut res = BinaryHeap::new();
 
     for i in 0..coords.len() {
@@ -102,54 +102,94 @@ fn get_edges(coords: &Vec<UnionFind<Coord>>) -> BinaryHeap<Reverse<Edge>> {
     res
 }
 
-pub fn part1() -> i64 {
-    let coords = parse_coordinates();
-
-    let mut edges = get_edges(&coords);
+const CONNECTIONS: Param<usize> = Param::new(
+    "connections",
+    1000,
+    "closest pairs to connect in part 1 (10 for the sample)",
+);
 
-    for _ in 0..1000 {
-        if let Some(Reverse(Edge { src, dst, .. })) = edges.pop() {
-            let mut src = UnionFind(Rc::clone(&coords.iter().find(|n| *n.data() == src).unwrap().0));
-            let mut dst = UnionFind(Rc::clone(&coords.iter().find(|n| *n.data() == dst).unwrap().0));
+const CIRCUITS: Param<usize> = Param::new("circuits", 3, "largest circuits to multiply in part 1");
 
-            _ = src.union(&mut dst);
-        }
-    }
+pub struct Playground {
+    coords: Vec<Coord>,
+    connections: usize,
+    circuits: usize,
+}
 
-    let union_by_count = coords.iter()
-        .map(|uf_node| *uf_node.find().data())
-        .counts();
+pub struct Day08;
 
-    let mut counts = union_by_count.into_values().collect::<Vec<_>>();
+impl Solution for Day08 {
+    type Input = Playground;
 
-    counts.sort();
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        self.parse_with(input, &Params::default())
+    }
 
-    counts.iter().cloned().rev().take(3).product::<usize>() as i64
-}
+    fn params(&self) -> Vec<ParamInfo> {
+        vec![CONNECTIONS.info(), CIRCUITS.info()]
+    }
 
-pub fn part2() -> i64 {
-    let coords = parse_coordinates();
+    fn parse_with(&self, input: &str, params: &Params) -> Result<Self::Input> {
+        Ok(Playground {
+            coords: parse_lines(input, Coord::try_from)?,
+            connections: CONNECTIONS.get(params)?,
+            circuits: CIRCUITS.get(params)?,
+        })
+    }
 
-    let mut edges = get_edges(&coords);
+    fn part1(&self, playground: &Self::Input) -> Result<Answer> {
+        let coords = make_sets(&playground.coords);
 
-    let mut res = 0i64;
+        let mut edges = get_edges(&coords);
 
-    while let Some(Reverse(Edge { src, dst, .. })) = edges.pop() {
-        let mut src = UnionFind(Rc::clone(&coords.iter().find(|n| *n.data() == src).unwrap().0));
-        let mut dst = UnionFind(Rc::clone(&coords.iter().find(|n| *n.data() == dst).unwrap().0));
+        for _ in 0..playground.connections {
+            if let Some(Reverse(Edge { src, dst, .. })) = edges.pop() {
+                let mut src = UnionFind(Rc::clone(&coords.iter().find(|n| *n.data() == src).unwrap().0));
+                let mut dst = UnionFind(Rc::clone(&coords.iter().find(|n| *n.data() == dst).unwrap().0));
 
-        _ = src.union(&mut dst);
+                _ = src.union(&mut dst);
+            }
+        }
 
         let union_by_count = coords.iter()
             .map(|uf_node| *uf_node.find().data())
             .counts();
 
-        res = src.data().x * dst.data().x;
+        let mut counts = union_by_count.into_values().collect::<Vec<_>>();
 
-        if union_by_count.len() == 1 {
-            break;
-        }
+        counts.sort();
+
+        crate::debug!("{} circuits after {} connections", counts.len(), playground.connections);
+
+        Ok(counts.iter().cloned().rev().take(playground.circuits).product::<usize>().into())
     }
 
-    res as i64
+    fn part2(&self, playground: &Self::Input) -> Result<Answer> {
+        let coords = make_sets(&playground.coords);
+
+        let mut edges = get_edges(&coords);
+
+        let mut res = 0i64;
+
+        while let Some(Reverse(Edge { src, dst, .. })) = edges.pop() {
+            let mut src = UnionFind(Rc::clone(&coords.iter().find(|n| *n.data() == src).unwrap().0));
+            let mut dst = UnionFind(Rc::clone(&coords.iter().find(|n| *n.data() == dst).unwrap().0));
+
+            _ = src.union(&mut dst);
+
+            let union_by_count = coords.iter()
+                .map(|uf_node| *uf_node.find().data())
+                .counts();
+
+            res = src.data().x * dst.data().x;
+
+            crate::trace!("connected {} and {}, {} circuits left", src.data(), dst.data(), union_by_count.len());
+
+            if union_by_count.len() == 1 {
+                break;
+            }
+        }
+
+        Ok(res.into())
+    }
 }
@@ -2,10 +2,14 @@ use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use itertools::Itertools;
 
+use crate::answer::Answer;
+use crate::error::{AocError, Result, parse_lines};
+use crate::solution::{Alternative, Solution};
+
 // WARNING: Current implementation is too slow :(
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-struct Coord {
+pub struct Coord {
     i: i64,
     j: i64,
 }
@@ -15,48 +19,169 @@ impl Coord {
         let Coord { i: i1, j: j1 } = self;
         let Coord { i: i2, j: j2 } = other;
 
-        ((i2 - i1).abs() as usize + 1) * ((j2 - j1).abs() as usize + 1)
+        (i2.abs_diff(*i1) as usize + 1) * (j2.abs_diff(*j1) as usize + 1)
     }
 }
 
 impl TryFrom<&str> for Coord {
-    type Error = ();
+    type Error = AocError;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         let parts = s.split(',').collect::<Vec<_>>();
 
         if parts.len() < 2 {
-            Err(())
+            Err(AocError::new(format!("expected `x,y`, found {:?}", s)))
         } else {
             let x = parts[0].parse::<i64>();
             let y = parts[1].parse::<i64>();
             match (x, y) {
                 (Ok(x), Ok(y)) => Ok(Coord { i: x, j: y }),
-                _ => Err(()),
+                _ => Err(AocError::new(format!("invalid coordinates {:?}", s))),
             }
         }
     }
 }
 
-fn parse_coords() -> impl Iterator<Item = Coord> {
-    std::io::stdin().lines().flatten()
-        .filter_map(|line| {
-            let parts = line.split(',').collect::<Vec<_>>();
+fn parse_coords(input: &str) -> Result<Vec<Coord>> {
+    parse_lines(input, Coord::try_from)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Coord>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_coords(input)
+    }
+
+    fn part1(&self, coords: &Self::Input) -> Result<Answer> {
+        coords.iter().cloned()
+            .combinations(2)
+            .map(|points| { points[0].area(&points[1]) })
+            .max()
+            .map(Answer::from)
+            .ok_or_else(|| AocError::new("need at least two red tiles"))
+    }
+
+    fn part2(&self, coords: &Self::Input) -> Result<Answer> {
+        let floor = Floor::new(coords.iter().cloned());
 
-            if parts.len() < 2 {
-                None
-            } else {
-                Coord::try_from(line.as_str()).ok()
+        let mut max_area = 0usize;
+
+        let mut rectangles = floor.vertices.iter().cloned()
+            .combinations(2).collect::<Vec<_>>();
+
+        rectangles.sort_by(|rect1, rect2| {
+            let area1 = rect1[0].area(&rect1[1]);
+            let area2 = rect2[0].area(&rect2[1]);
+            area2.cmp(&area1)
+        });
+
+        for rectangle in rectangles.iter() {
+            let (p, q) = (rectangle[0], rectangle[1]);
+
+            let all_coords_are_inside_polygon = floor
+                .rect_edges(p, q)
+                .into_iter()
+                .all(|coord| floor.is_coord_inside_polygon(coord));
+
+            if all_coords_are_inside_polygon {
+                max_area = std::cmp::max(max_area, p.area(&q));
+                break;
             }
-        })
+
+            crate::trace!("rectangle {:?} to {:?} rejected, area {}", p, q, p.area(&q));
+        }
+
+        Ok(max_area.into())
+    }
+
+    fn alternatives(&self) -> Vec<Alternative<Self::Input>> {
+        vec![Alternative { name: "compressed", part: 2, solve: max_area_compressed }]
+    }
 }
 
-pub fn part1() -> i64 {
-    parse_coords()
-        .combinations(2)
-        .map(|points| { points[0].area(&points[1]) as i64 })
+/// Part 2 on the floor compressed to the rows and columns that have a red
+/// tile, using prefix sums to check each rectangle in constant time.
+#[allow(clippy::ptr_arg)]
+fn max_area_compressed(coords: &Vec<Coord>) -> Result<Answer> {
+    // Each row (column) with a red tile gets a cell, and so does every gap
+    // between two of them, as well as the outer ring that has none.
+    let axis = |f: fn(&Coord) -> i64| {
+        let values = coords.iter().map(f).sorted().dedup().collect::<Vec<_>>();
+        let mut indices = Vec::with_capacity(values.len());
+
+        for (k, v) in values.iter().enumerate() {
+            indices.push(match k {
+                0 => 1,
+                _ => indices[k - 1] + if v - values[k - 1] > 1 { 2 } else { 1 },
+            });
+        }
+
+        (values, indices)
+    };
+    let (is, js) = (axis(|c| c.i), axis(|c| c.j));
+
+    let index = |(values, indices): &(Vec<i64>, Vec<usize>), v: i64| indices[values.binary_search(&v).unwrap()];
+    let size = |(_, indices): &(Vec<i64>, Vec<usize>)| indices.last().map_or(1, |last| last + 2);
+    let (rows, cols) = (size(&is), size(&js));
+
+    crate::debug!("compressed the floor to {} x {} cells", rows, cols);
+
+    let mut boundary = vec![vec![false; cols]; rows];
+
+    for (n, (u, v)) in coords.iter().circular_tuple_windows().enumerate() {
+        if u.i != v.i && u.j != v.j {
+            return Err(AocError::new("not in the same row or column as the next red tile").with_line(n + 1));
+        }
+
+        let (ui, vi) = (index(&is, u.i), index(&is, v.i));
+        let (uj, vj) = (index(&js, u.j), index(&js, v.j));
+
+        for row in boundary.iter_mut().take(ui.max(vi) + 1).skip(ui.min(vi)) {
+            row[uj.min(vj)..=uj.max(vj)].fill(true);
+        }
+    }
+
+    // Flood fill from the outer ring, which has no red tiles.
+    let mut outside = vec![vec![false; cols]; rows];
+    let mut stack = vec![(0usize, 0usize)];
+    outside[0][0] = true;
+
+    while let Some((r, c)) = stack.pop() {
+        let neighbors = [(r.wrapping_sub(1), c), (r + 1, c), (r, c.wrapping_sub(1)), (r, c + 1)];
+
+        for (r, c) in neighbors {
+            if r < rows && c < cols && !boundary[r][c] && !outside[r][c] {
+                outside[r][c] = true;
+                stack.push((r, c));
+            }
+        }
+    }
+
+    // `inside[r][c]` counts the cells of `[0, r) x [0, c)` that are red or green.
+    let mut inside = vec![vec![0usize; cols + 1]; rows + 1];
+
+    for r in 0..rows {
+        for c in 0..cols {
+            inside[r + 1][c + 1] = inside[r][c + 1] + inside[r + 1][c] - inside[r][c] + !outside[r][c] as usize;
+        }
+    }
+
+    Ok(coords.iter()
+        .tuple_combinations()
+        .filter(|&(p, q)| {
+            let (r1, r2) = (index(&is, p.i.min(q.i)), index(&is, p.i.max(q.i)));
+            let (c1, c2) = (index(&js, p.j.min(q.j)), index(&js, p.j.max(q.j)));
+
+            let count = inside[r2 + 1][c2 + 1] + inside[r1][c1] - inside[r1][c2 + 1] - inside[r2 + 1][c1];
+            count == (r2 - r1 + 1) * (c2 - c1 + 1)
+        })
+        .map(|(p, q)| p.area(q))
         .max()
-        .unwrap()
+        .unwrap_or(0)
+        .into())
 }
 
 struct Floor {
@@ -65,13 +190,13 @@ struct Floor {
 }
 
 impl Floor {
-    fn new<'a>(vertices: impl Iterator<Item = Coord>) -> Self {
+    fn new(vertices: impl Iterator<Item = Coord>) -> Self {
         let vertices = vertices.collect::<Vec<_>>();
 
         let cache = Rc::new(RefCell::new(HashMap::new()));
 
         for coord in vertices.iter() {
-            cache.borrow_mut().insert(coord.clone(), true);
+            cache.borrow_mut().insert(*coord, true);
         }
 
         Self {
@@ -163,42 +288,14 @@ impl Floor {
     }
 }
 
-pub fn part2() -> i64 {
-    let floor = Floor::new(parse_coords());
-
-    let mut max_area = 0usize;
-
-    let mut rectangles = floor.vertices.iter().cloned()
-        .combinations(2).collect::<Vec<_>>();
-
-    rectangles.sort_by(|rect1, rect2| {
-        let area1 = rect1[0].area(&rect1[1]);
-        let area2 = rect2[0].area(&rect2[1]);
-        area2.cmp(&area1)
-    });
-
-    for i in 0..rectangles.len() {
-        let (p, q) = (rectangles[i][0], rectangles[i][1]);
-
-        let all_coords_are_inside_polygon = floor
-            .rect_edges(p, q)
-            .into_iter()
-            .all(|coord| floor.is_coord_inside_polygon(coord));
-
-        if all_coords_are_inside_polygon {
-            max_area = std::cmp::max(max_area, p.area(&q));
-            break;
-        }
-    }
-
-    max_area as i64
-}
-
 #[cfg(test)]
 mod test {
-    use crate::y2025::day09::{Coord, Floor};
+    use crate::answer::Answer;
+    use crate::solution::Solution;
+    use crate::y2025::day09::{Coord, Day09, Floor, max_area_compressed, parse_coords};
 
     #[test]
+    #[allow(clippy::useless_vec)]
     fn test_floor() {
         let coords = vec![
             Coord { i: 7, j: 1 },
@@ -217,4 +314,31 @@ mod test {
 
         assert_eq!(edges.len(), coords.len())
     }
+
+    #[test]
+    fn test_max_area_compressed() {
+        let coords = vec![
+            Coord { i: 7, j: 1 },
+            Coord { i: 11, j: 1 },
+            Coord { i: 11, j: 7 },
+            Coord { i: 9, j: 7 },
+            Coord { i: 9, j: 5 },
+            Coord { i: 2, j: 5 },
+            Coord { i: 2, j: 3 },
+            Coord { i: 7, j: 3 },
+        ];
+
+        assert_eq!(max_area_compressed(&coords), Ok(Answer::from(24usize)));
+        assert!(max_area_compressed(&vec![Coord { i: 0, j: 0 }, Coord { i: 1, j: 1 }]).is_err());
+    }
+
+    #[test]
+    fn test_max_area_compressed_adjacent() {
+        // Rows 5 and 6 are next to each other, so there is no gap between
+        // them that could be outside.
+        let coords = parse_coords("0,0\n5,0\n5,5\n6,5\n6,0\n10,0\n10,10\n0,10").unwrap();
+
+        assert_eq!(Day09.part2(&coords), Ok(Answer::from(121usize)));
+        assert_eq!(max_area_compressed(&coords), Ok(Answer::from(121usize)));
+    }
 }
@@ -1,19 +1,19 @@
 use std::collections::{HashMap, VecDeque, hash_map::Entry};
 
-use good_lp::{Expression, Solution, SolverModel, constraint, default_solver, variable, variables};
+#[cfg(feature = "lp")]
+use good_lp::{Expression, Solution as _, SolverModel, constraint, default_solver, variable, variables};
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+use crate::answer::Answer;
+use crate::error::{AocError, Result, parse_lines};
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
 enum Status {
+    #[default]
     Off,
     On,
 }
 
-impl Default for Status {
-    fn default() -> Self {
-        Self::Off
-    }
-}
-
 #[derive(PartialEq, Eq, Hash, Clone, Debug)]
 struct Indicator {
     inner: Vec<Status>,
@@ -39,44 +39,46 @@ impl Indicator {
 }
 
 impl TryFrom<&str> for Indicator {
-    type Error = ();
+    type Error = AocError;
 
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        let mut inner = Vec::new();
+    fn try_from(s: &str) -> Result<Self> {
+        let s = s.strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or_else(|| AocError::new(format!("expected `[...]`, found `{}`", s)))?;
 
-        for c in s.chars() {
-            match c {
-                '.' => inner.push(Status::Off),
-                '#' => inner.push(Status::On),
-                _ => {},
-            }
-        }
+        let inner = s.chars()
+            .map(|c| match c {
+                '.' => Ok(Status::Off),
+                '#' => Ok(Status::On),
+                _ => Err(AocError::new(format!("unexpected indicator light `{}`", c))),
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         Ok(Self { inner })
     }
 }
 
+/// Parses a comma-separated list of numbers enclosed in `open` and `close`.
+fn parse_list(s: &str, open: char, close: char) -> Result<Vec<usize>> {
+    let inner = s.strip_prefix(open)
+        .and_then(|s| s.strip_suffix(close))
+        .ok_or_else(|| AocError::new(format!("expected `{}...{}`, found `{}`", open, close, s)))?;
+
+    inner.split(',')
+        .map(|n| n.parse::<usize>().map_err(|_| AocError::new(format!("invalid number `{}`", n))))
+        .collect()
+}
+
 #[derive(PartialEq, Eq, Hash, Clone, Debug)]
 struct JoltageLevels {
     inner: Vec<usize>,
 }
 
 impl TryFrom<&str> for JoltageLevels {
-    type Error = ();
-
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        let s = s.chars()
-            .skip(1).
-            take_while(|&c| { c != '}'})
-            .collect::<String>();
+    type Error = AocError;
 
-        let parts = s.split(',').collect::<Vec<_>>();
-
-        let inner = parts.into_iter()
-            .filter_map(|s| s.parse::<usize>().ok())
-            .collect::<Vec<_>>();
-
-        Ok(Self { inner })
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(Self { inner: parse_list(s, '{', '}')? })
     }
 }
 
@@ -86,49 +88,53 @@ struct Button {
 }
 
 impl TryFrom<&str> for Button {
-    type Error = ();
-
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        let s = s.chars()
-            .skip(1).
-            take_while(|&c| { c != ')'})
-            .collect::<String>();
-
-        let parts = s.split(',').collect::<Vec<_>>();
+    type Error = AocError;
 
-        let inner = parts.into_iter()
-            .filter_map(|s| s.parse::<usize>().ok())
-            .collect::<Vec<_>>();
-
-        Ok(Self { inner })
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(Self { inner: parse_list(s, '(', ')')? })
     }
 }
 
 #[derive(Debug)]
-struct Manual {
+pub struct Manual {
     target_indicator: Indicator,
+    #[cfg_attr(not(feature = "lp"), allow(dead_code))]
     target_joltage_levels: JoltageLevels,
     buttons: Vec<Button>,
 }
 
 impl TryFrom<&str> for Manual {
-    type Error = ();
+    type Error = AocError;
 
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        let parts = s.split(' ').collect::<Vec<_>>();
+    fn try_from(s: &str) -> Result<Self> {
+        let parts = s.split_whitespace().collect::<Vec<_>>();
 
         if parts.len() < 3 {
-            return Err(());
+            return Err(AocError::new("expected an indicator, buttons and joltage levels"));
         }
 
         let target_indicator = Indicator::try_from(parts[0])?;
 
-        let buttons = (1..parts.len() - 1)
-            .filter_map(|i| Button::try_from(parts[i]).ok() )
-            .collect::<Vec<_>>();
+        let buttons = parts[1..parts.len() - 1].iter()
+            .map(|&part| Button::try_from(part))
+            .collect::<Result<Vec<_>>>()?;
 
         let target_joltage_levels = JoltageLevels::try_from(parts[parts.len() - 1])?;
 
+        let len = target_indicator.inner.len();
+
+        if let Some(&index) = buttons.iter().flat_map(|button| &button.inner).find(|&&index| index >= len) {
+            return Err(AocError::new(format!("button wires light {} but there are only {}", index, len)));
+        }
+
+        if target_joltage_levels.inner.len() != len {
+            return Err(AocError::new(format!(
+                "expected {} joltage levels, found {}",
+                len,
+                target_joltage_levels.inner.len(),
+            )));
+        }
+
         Ok(Self {
             target_indicator,
             buttons,
@@ -147,7 +153,7 @@ impl Manual {
             .map(|button| indicator.apply(button))
     }
 
-    fn num_fewest_toggles_for_indicator(&self) -> usize {
+    fn num_fewest_toggles_for_indicator(&self) -> Option<usize> {
         let s = self.src_indicator();
 
         let mut dist: HashMap<Indicator, usize> = HashMap::new();
@@ -156,10 +162,17 @@ impl Manual {
         let mut q = VecDeque::new();
         q.push_front(s.clone());
 
+        let mut depth = 0;
+
         'out: while !q.is_empty() {
             if let Some(curr) = q.pop_back() {
                 let curr_dist = *dist.get(&curr).unwrap();
 
+                if curr_dist > depth {
+                    depth = curr_dist;
+                    crate::trace!("BFS frontier size {} at distance {}", q.len() + 1, depth);
+                }
+
                 for neighbor in self.next_indicators(&curr) {
                     if let Entry::Vacant(e) = dist.entry(neighbor.clone()) {
                         e.insert(curr_dist + 1);
@@ -173,10 +186,11 @@ impl Manual {
             }
         }
 
-        dist[&self.target_indicator]
+        dist.get(&self.target_indicator).copied()
     }
 
-    fn num_fewest_toggles_for_joltage_levels(&self) -> usize {
+    #[cfg(feature = "lp")]
+    fn num_fewest_toggles_for_joltage_levels(&self) -> Option<usize> {
         let mut vars = variables!();
 
         let mut presses = Vec::new();
@@ -199,32 +213,46 @@ impl Manual {
             problem = problem.with(constraint!(sum == target as i32));
         }
 
-        // NOTE: if `.solve()` fails, it's a problem... but i won't fix.
-        if let Ok(solution) = problem.solve() {
-            presses.iter()
-                .map(|&v| solution.value(v) as i32)
-                .sum::<i32>() as usize
-        } else {
-            0
-        }
+        let solution = problem.solve().ok()?;
+
+        Some(presses.iter()
+            .map(|&v| solution.value(v).round() as usize)
+            .sum())
     }
 }
 
-fn parse_manuals() -> impl Iterator<Item = Manual> {
-    std::io::stdin().lines().flatten()
-        .flat_map(|line| Manual::try_from(line.as_str()).ok())
+/// Sums `f` over every machine, failing on the first one that cannot be
+/// configured.
+fn total(manuals: &[Manual], f: impl Fn(&Manual) -> Option<usize>) -> Result<Answer> {
+    manuals.iter()
+        .enumerate()
+        .map(|(i, manual)| f(manual).ok_or_else(|| AocError::new("machine has no solution").with_line(i + 1)))
+        .sum::<Result<usize>>()
+        .map(Answer::from)
 }
 
-pub fn part1() -> i64 {
-    parse_manuals()
-        .map(|manual| manual.num_fewest_toggles_for_indicator())
-        .sum::<usize>() as i64
-}
+pub struct Day10;
 
-pub fn part2() -> i64 {
-    parse_manuals()
-        .map(|manual| manual.num_fewest_toggles_for_joltage_levels())
-        .sum::<usize>() as i64
+impl Solution for Day10 {
+    type Input = Vec<Manual>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_lines(input, Manual::try_from)
+    }
+
+    fn part1(&self, manuals: &Self::Input) -> Result<Answer> {
+        total(manuals, Manual::num_fewest_toggles_for_indicator)
+    }
+
+    #[cfg(feature = "lp")]
+    fn part2(&self, manuals: &Self::Input) -> Result<Answer> {
+        total(manuals, Manual::num_fewest_toggles_for_joltage_levels)
+    }
+
+    #[cfg(not(feature = "lp"))]
+    fn part2(&self, _manuals: &Self::Input) -> Result<Answer> {
+        Err(AocError::unavailable("enable the `lp` feature for an LP solver"))
+    }
 }
 
 #[cfg(test)]
@@ -241,23 +269,33 @@ mod test {
     }
 
     #[test]
+    fn test_manual_errors() {
+        assert!(Manual::try_from("[.##.] (3) (1,3)").is_err());
+        assert!(Manual::try_from("[.#x.] (3) {3,5,4,7}").is_err());
+        assert!(Manual::try_from("[.##.] (4) {3,5,4,7}").is_err());
+        assert!(Manual::try_from("[.##.] (3) {3,5,4}").is_err());
+        assert!(Manual::try_from("[.##.] (3) {3,5,4,7}").is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "lp")]
     fn test_num_fewest_toggles_for_joltage() {
         let s = "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
         assert_eq!(
             Manual::try_from(s).unwrap().num_fewest_toggles_for_joltage_levels(),
-            11
+            Some(11)
         );
 
         let s = "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
         assert_eq!(
             Manual::try_from(s).unwrap().num_fewest_toggles_for_joltage_levels(),
-            12
+            Some(12)
         );
 
         let s = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
         assert_eq!(
             Manual::try_from(s).unwrap().num_fewest_toggles_for_joltage_levels(),
-            10
+            Some(10)
         );
     }
 }
@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 
+use crate::answer::Answer;
+use crate::error::{AocError, Result, parse_lines};
+use crate::solution::Solution;
 use crate::utils::dag::Dag;
 
-struct Graph {
+pub struct Graph {
     you: String,
     out: String,
     svr: String,
@@ -11,32 +14,6 @@ struct Graph {
     dag: Dag<String>,
 }
 
-fn parse_graph() -> Graph {
-    let mut dag = Dag::new();
-
-    std::io::stdin().lines().flatten()
-        .for_each(|line| {
-            match line.clone().split_once(": ") {
-                Some((src, dst_edges)) => {
-                    dst_edges.split(' ')
-                        .for_each(|dst| {
-                            dag.add_edge(&src.to_string(), &dst.to_string());
-                        });
-                },
-                _ => {},
-            }
-        });
-
-    Graph {
-        you: String::from("you"),
-        out: String::from("out"),
-        svr: String::from("svr"),
-        fft: String::from("fft"),
-        dac: String::from("dac"),
-        dag,
-    }
-}
-
 impl Graph {
     fn dfs(
         &self,
@@ -84,26 +61,53 @@ impl Graph {
     }
 }
 
-pub fn part1() -> i64 {
-    let g = parse_graph();
+pub struct Day11;
 
-    g.num_unique_paths(&g.you, &g.out) as i64
-}
+impl Solution for Day11 {
+    type Input = Graph;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let mut dag = Dag::new();
+
+        let edges = parse_lines(input, |line| {
+            line.split_once(": ")
+                .ok_or_else(|| AocError::new("expected `<device>: <outputs>`"))
+        })?;
 
-pub fn part2() -> i64 {
-    let g = parse_graph();
+        for (src, dst_edges) in edges {
+            dst_edges.split(' ')
+                .for_each(|dst| {
+                    dag.add_edge(&src.to_string(), &dst.to_string());
+                });
+        }
+
+        Ok(Graph {
+            you: String::from("you"),
+            out: String::from("out"),
+            svr: String::from("svr"),
+            fft: String::from("fft"),
+            dac: String::from("dac"),
+            dag,
+        })
+    }
 
-    let svr_to_fft = g.num_unique_paths(&g.svr, &g.fft);
+    fn part1(&self, g: &Self::Input) -> Result<Answer> {
+        Ok(g.num_unique_paths(&g.you, &g.out).into())
+    }
+
+    fn part2(&self, g: &Self::Input) -> Result<Answer> {
+        let svr_to_fft = g.num_unique_paths(&g.svr, &g.fft);
 
-    let fft_to_dac = g.num_unique_paths(&g.fft, &g.dac);
+        let fft_to_dac = g.num_unique_paths(&g.fft, &g.dac);
 
-    let dac_to_out = g.num_unique_paths(&g.dac, &g.out);
+        let dac_to_out = g.num_unique_paths(&g.dac, &g.out);
 
-    let svr_to_dac = g.num_unique_paths(&g.svr, &g.dac);
+        let svr_to_dac = g.num_unique_paths(&g.svr, &g.dac);
 
-    let dac_to_fft = g.num_unique_paths(&g.dac, &g.fft);
+        let dac_to_fft = g.num_unique_paths(&g.dac, &g.fft);
 
-    let fft_to_out = g.num_unique_paths(&g.fft, &g.out);
+        let fft_to_out = g.num_unique_paths(&g.fft, &g.out);
 
-    (svr_to_fft * fft_to_dac * dac_to_out + svr_to_dac * dac_to_fft * fft_to_out) as i64
+        Ok((svr_to_fft * fft_to_dac * dac_to_out + svr_to_dac * dac_to_fft * fft_to_out).into())
+    }
 }
\ No newline at end of file
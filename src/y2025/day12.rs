@@ -1,7 +1,21 @@
-pub fn part1() -> i64 {
-    todo!()
-}
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::Solution;
 
-pub fn part2() -> i64 {
-    todo!()
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = ();
+
+    fn parse(&self, _input: &str) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn part1(&self, _input: &Self::Input) -> Result<Answer> {
+        todo!()
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        todo!()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,43 @@
+//! Checks of the command line interface, running the `aoc25` binary.
+
+use std::process::{Command, Output, Stdio};
+
+// Unused without the `y2025` feature, which every test here needs.
+#[allow(dead_code)]
+fn aoc25(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc25"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .stdin(Stdio::null())
+        .output()
+        .unwrap()
+}
+
+#[test]
+#[cfg(feature = "y2025")]
+fn test_impl_falls_back_to_default() {
+    let output = aoc25(&["-y", "2025", "-d", "9", "--sample", "--impl", "compressed"]);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Part 1: 50\nPart 2: 24\n");
+
+    let output = aoc25(&["-y", "2025", "-d", "9", "-p", "1", "--sample", "--impl", "compressed"]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: no selected part has an implementation `compressed` (part 1: default)\n",
+    );
+}
+
+#[test]
+#[cfg(feature = "y2025")]
+fn test_accept_real_only() {
+    let output = aoc25(&["accept", "-y", "2025", "-d", "1", "--sample"]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: only answers for the real input can be accepted, see `--real`\n",
+    );
+}
@@ -0,0 +1,39 @@
+//! End-to-end checks of every solver against its sample input. The tests
+//! themselves are generated by `build.rs` from `inputs/<year>/answers.toml`.
+
+use std::path::Path;
+
+use aoc25::params::Params;
+
+// Unused if no year with samples is enabled.
+#[allow(dead_code)]
+fn check(year: u16, day: u8, part: u8, path: &str, params: &[(&str, &str)], expected: &str) {
+    let input = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(path))
+        .unwrap_or_else(|e| panic!("{}: {}", path, e));
+
+    let params = Params::new(params.iter().map(|&(key, value)| (key.to_string(), value.to_string())));
+
+    let answer = aoc25::solve_with(year, day, part, &input, &params)
+        .unwrap_or_else(|e| panic!("{} part {}: {}", path, part, e));
+
+    assert_eq!(answer.to_string(), expected, "{} part {}", path, part);
+}
+
+include!(concat!(env!("OUT_DIR"), "/samples.rs"));
+
+#[test]
+#[cfg(feature = "y2025")]
+fn test_solve_errors() {
+    assert_eq!(
+        aoc25::solve(2025, 26, 1, "").unwrap_err().to_string(),
+        "2025 day 26 not implemented",
+    );
+    assert_eq!(
+        aoc25::solve(2025, 1, 1, "L1\nX2\n").unwrap_err().to_string(),
+        "line 2: expected `L` or `R`, found \"X2\"",
+    );
+    assert_eq!(
+        aoc25::solve(2025, 12, 1, "").unwrap_err().to_string(),
+        "2025 day 12 part 1: not implemented",
+    );
+}